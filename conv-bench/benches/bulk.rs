@@ -0,0 +1,50 @@
+#[macro_use] extern crate criterion;
+extern crate conv;
+
+use criterion::{black_box, Criterion};
+use conv::{RoundToNearest, Saturating};
+use conv::bulk;
+use conv::bulk::simd;
+
+const LEN: usize = 1 << 16;
+
+fn samples() -> Vec<f32> {
+    (0..LEN).map(|i| ((i as f32) * 0.37).sin() * 32000.0).collect()
+}
+
+fn bytes() -> Vec<u8> {
+    (0..LEN).map(|i| (i * 31) as u8).collect()
+}
+
+fn f32_to_i16(c: &mut Criterion) {
+    let src = samples();
+    let mut dst = vec![0i16; LEN];
+
+    c.bench_function("f32_to_i16_round/scalar", |b| b.iter(|| {
+        bulk::approx_into_slice::<_, _, RoundToNearest>(black_box(&src), &mut dst).unwrap()
+    }));
+    c.bench_function("f32_to_i16_round/simd", |b| b.iter(|| {
+        simd::approx_into_slice::<_, _, RoundToNearest>(black_box(&src), &mut dst).unwrap()
+    }));
+    c.bench_function("f32_to_i16_sat/scalar", |b| b.iter(|| {
+        bulk::approx_into_slice::<_, _, Saturating>(black_box(&src), &mut dst).unwrap()
+    }));
+    c.bench_function("f32_to_i16_sat/simd", |b| b.iter(|| {
+        simd::approx_into_slice::<_, _, Saturating>(black_box(&src), &mut dst).unwrap()
+    }));
+}
+
+fn u8_to_f32(c: &mut Criterion) {
+    let src = bytes();
+    let mut dst = vec![0.0f32; LEN];
+
+    c.bench_function("u8_to_f32/scalar", |b| b.iter(|| {
+        bulk::value_into_slice(black_box(&src), &mut dst).unwrap()
+    }));
+    c.bench_function("u8_to_f32/simd", |b| b.iter(|| {
+        simd::value_into_slice(black_box(&src), &mut dst).unwrap()
+    }));
+}
+
+criterion_group!(benches, f32_to_i16, u8_to_f32);
+criterion_main!(benches);
@@ -0,0 +1,440 @@
+/*!
+This crate provides custom derives for the conversion traits defined by the [`conv`](https://crates.io/crates/conv) crate.
+
+These are intended as a replacement for the `TryFrom!` macro, which must be used via [`custom_derive!`](https://crates.io/crates/custom_derive).
+
+# `#[derive(TryFrom)]`
+
+When applied to an `enum` consisting of unitary variants, this derives an implementation of `TryFrom<$repr>` for each source type named by a `#[conv(repr = "...")]` attribute.  If there is no such attribute, the `enum`'s `#[repr(...)]` attribute is used instead.
+
+If a conversion fails (due to there being no matching variant for the value `src`), then the conversion returns `Err(Unrepresentable(src))`.
+
+Alternatively, exactly one tuple variant with a single field of the source type may be marked with `#[conv(other)]`, as with `TryFrom!`.  This variant absorbs any value which does not match another variant, and the conversion becomes infallible, using `NoError` as its error type.  If there is more than one source type, they must all be the type of this field.
+
+When applied to a `struct` with a single field, this derives an implementation of `TryFrom<$src>` for each source type named by a `#[conv(from = "...")]` attribute.  The conversion is forwarded to the field's type.
+
+# `#[derive(ValueFrom)]` and `#[derive(ApproxFrom)]`
+
+These may only be applied to `struct`s with a single field.  For each source type named by a `#[conv(from = "...")]` attribute, they derive an implementation of `ValueFrom<$src>` and `ApproxFrom<$src, Scheme>` (for every `Scheme`) respectively.  The conversion is forwarded to the field's type.
+
+# Example
+
+```
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::{ApproxFrom, RoundToNearest, TryFrom, Unrepresentable, ValueFrom};
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[conv(repr = "i32")]
+enum Colours {
+    Red = 0,
+    Green = 5,
+    Blue
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0x90,
+    Ret = 0xc3,
+    #[conv(other)]
+    Unknown(u8),
+}
+
+#[derive(Debug, PartialEq, ValueFrom, ApproxFrom)]
+#[conv(from = "u8", from = "f32")]
+struct Metres(i16);
+
+fn main() {
+    assert_eq!(Colours::try_from(0), Ok(Colours::Red));
+    assert_eq!(Colours::try_from(1), Err(Unrepresentable(1)));
+    assert_eq!(Colours::try_from(6), Ok(Colours::Blue));
+    assert_eq!(Opcode::try_from(0x90), Ok(Opcode::Nop));
+    assert_eq!(Opcode::try_from(0x0f), Ok(Opcode::Unknown(0x0f)));
+
+    assert_eq!(Metres::value_from(42u8), Ok(Metres(42)));
+    assert_eq!(<Metres as ApproxFrom<_, RoundToNearest>>::approx_from(41.5f32), Ok(Metres(42)));
+}
+```
+
+A discriminant which does not fit in a source type is also an error, rather than silently truncated:
+
+```compile_fail
+#[macro_use] extern crate conv_derive;
+
+#[derive(TryFrom)]
+#[repr(u16)]
+#[conv(repr = "u8")]
+enum Wide { A = 1, B = 300 }
+
+fn main() {}
+```
+
+Marking a unitary variant, or a variant with more than one field, with `#[conv(other)]` is an error:
+
+```compile_fail
+#[macro_use] extern crate conv_derive;
+
+#[derive(TryFrom)]
+#[repr(u8)]
+enum Opcode {
+    Nop = 0x90,
+    #[conv(other)]
+    Unknown,
+}
+
+fn main() {}
+```
+*/
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Error, Fields, Ident, LitStr, Meta, Token, Type};
+
+/**
+Derives `TryFrom` for `enum`s or single-field `struct`s.
+
+See the [crate documentation](./index.html) for details.
+*/
+#[proc_macro_derive(TryFrom, attributes(conv))]
+pub fn derive_try_from(input: TokenStream) -> TokenStream {
+    expand(input, |input, attrs| match input.data {
+        Data::Enum(..) => try_from_enum(input, attrs),
+        _ => newtype(input, attrs, "TryFrom", newtype_try_from),
+    })
+}
+
+/**
+Derives `ValueFrom` for single-field `struct`s.
+
+See the [crate documentation](./index.html) for details.
+*/
+#[proc_macro_derive(ValueFrom, attributes(conv))]
+pub fn derive_value_from(input: TokenStream) -> TokenStream {
+    expand(input, |input, attrs| newtype(input, attrs, "ValueFrom", newtype_value_from))
+}
+
+/**
+Derives `ApproxFrom` for single-field `struct`s.
+
+See the [crate documentation](./index.html) for details.
+*/
+#[proc_macro_derive(ApproxFrom, attributes(conv))]
+pub fn derive_approx_from(input: TokenStream) -> TokenStream {
+    expand(input, |input, attrs| newtype(input, attrs, "ApproxFrom", newtype_approx_from))
+}
+
+fn expand<F>(input: TokenStream, f: F) -> TokenStream
+where F: FnOnce(&DeriveInput, &ConvAttrs) -> Result<TokenStream2, Error> {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    ConvAttrs::parse(&input)
+        .and_then(|attrs| f(&input, &attrs))
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// The contents of all `#[conv(...)]` attributes on an item.
+struct ConvAttrs {
+    reprs: Vec<Type>,
+    froms: Vec<Type>,
+}
+
+impl ConvAttrs {
+    fn parse(input: &DeriveInput) -> Result<ConvAttrs, Error> {
+        let mut attrs = ConvAttrs {
+            reprs: vec![],
+            froms: vec![],
+        };
+
+        for attr in input.attrs.iter().filter(|a| a.path().is_ident("conv")) {
+            attr.parse_nested_meta(|meta| {
+                let dest = if meta.path.is_ident("repr") {
+                    &mut attrs.reprs
+                } else if meta.path.is_ident("from") {
+                    &mut attrs.froms
+                } else {
+                    return Err(meta.error("unsupported conv attribute; expected `repr` or `from`"));
+                };
+                let lit: LitStr = meta.value()?.parse()?;
+                dest.push(lit.parse()?);
+                Ok(())
+            })?;
+        }
+
+        Ok(attrs)
+    }
+}
+
+/// Returns the integer type named in the item's `#[repr(...)]` attribute, if any.
+fn repr_int(input: &DeriveInput) -> Result<Option<Type>, Error> {
+    const INTS: &[&str] = &[
+        "i8", "i16", "i32", "i64", "i128", "isize",
+        "u8", "u16", "u32", "u64", "u128", "usize",
+    ];
+
+    let mut result = None;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        let metas = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated)?;
+        for meta in metas {
+            if let Meta::Path(ref path) = meta {
+                if INTS.iter().any(|i| path.is_ident(i)) {
+                    result = Some(syn::parse_quote!(#path));
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+fn try_from_enum(input: &DeriveInput, attrs: &ConvAttrs) -> Result<TokenStream2, Error> {
+    let name = &input.ident;
+
+    if let Some(from) = attrs.froms.first() {
+        return Err(Error::new_spanned(from,
+            "`from` is not supported on enums; use `repr` to name the source type"));
+    }
+
+    let variants = match input.data {
+        Data::Enum(ref data) => &data.variants,
+        _ => unreachable!(),
+    };
+
+    let mut var_names = vec![];
+    let mut other = None;
+    for var in variants {
+        if is_other(var)? {
+            match var.fields {
+                Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => (),
+                _ => return Err(Error::new_spanned(&var.ident, format!(
+                    "cannot derive TryFrom for {}, as the #[conv(other)] variant {} \
+                     must be a tuple variant with a single field",
+                    name, var.ident))),
+            }
+            if other.is_some() {
+                return Err(Error::new_spanned(&var.ident, format!(
+                    "cannot derive TryFrom for {}, due to more than one #[conv(other)] variant",
+                    name)));
+            }
+            other = Some(&var.ident);
+            continue;
+        }
+        match var.fields {
+            Fields::Unit => var_names.push(&var.ident),
+            _ => return Err(Error::new_spanned(&var.fields, format!(
+                "cannot derive TryFrom for {}, due to non-unitary variant {} not marked with #[conv(other)]",
+                name, var.ident))),
+        }
+    }
+
+    let enum_repr = repr_int(input)?;
+    let reprs = match (attrs.reprs.is_empty(), enum_repr.clone()) {
+        (false, _) => attrs.reprs.clone(),
+        (true, Some(repr)) => vec![repr],
+        (true, None) => return Err(Error::new(name.span(),
+            "cannot derive TryFrom without a source type; \
+             add a `#[conv(repr = \"...\")]` or `#[repr(...)]` attribute")),
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    // Rejects any discriminant which would be truncated by the cast to the source type, rather than silently never matching it.
+    let range_checks = |base: TokenStream2, repr: &Type| {
+        let checks = var_names.iter().map(|var| {
+            let msg = format!("discriminant of {}::{} does not fit in {}", name, var, quote!(#repr));
+            quote_spanned! {var.span()=>
+                const _: () = assert!(#base::#var as i128 == #base::#var as #repr as i128, #msg);
+            }
+        });
+        quote!(#(#checks)*)
+    };
+
+    let impls = reprs.iter().map(|repr| match other {
+        None => {
+            let checks = range_checks(quote!(#name), repr);
+            quote_spanned! {repr.span()=>
+                impl #impl_generics ::conv::TryFrom<#repr> for #name #ty_generics #where_clause {
+                    type Err = ::conv::errors::Unrepresentable<#repr>;
+                    #[inline]
+                    fn try_from(src: #repr) -> ::conv::macros::re_export::Result<Self, Self::Err> {
+                        #checks
+                        #(
+                            if src == Self::#var_names as #repr {
+                                return ::conv::macros::re_export::Ok(Self::#var_names);
+                            }
+                        )*
+                        ::conv::macros::re_export::Err(::conv::errors::Unrepresentable(src))
+                    }
+                }
+            }
+        },
+        Some(other) => {
+            // An enum with a non-unitary variant cannot be cast with `as`, so we mirror its discriminants in one which can.
+            let discrs = variants.iter().map(|var| {
+                let ident = &var.ident;
+                match var.discriminant {
+                    Some((_, ref expr)) => quote!(#ident = #expr),
+                    None => quote!(#ident),
+                }
+            });
+            let enum_repr = enum_repr.iter();
+            let checks = range_checks(quote!(Discriminant), repr);
+            quote_spanned! {repr.span()=>
+                impl #impl_generics ::conv::TryFrom<#repr> for #name #ty_generics #where_clause {
+                    type Err = ::conv::errors::NoError;
+                    #[inline]
+                    fn try_from(src: #repr) -> ::conv::macros::re_export::Result<Self, Self::Err> {
+                        #[allow(dead_code)]
+                        #(#[repr(#enum_repr)])*
+                        enum Discriminant { #(#discrs,)* }
+                        #checks
+                        #(
+                            if src == Discriminant::#var_names as #repr {
+                                return ::conv::macros::re_export::Ok(Self::#var_names);
+                            }
+                        )*
+                        ::conv::macros::re_export::Ok(Self::#other(src))
+                    }
+                }
+            }
+        },
+    });
+
+    Ok(quote!(#(#impls)*))
+}
+
+/// Returns whether the variant is marked with `#[conv(other)]`.
+fn is_other(var: &syn::Variant) -> Result<bool, Error> {
+    let mut other = false;
+    for attr in var.attrs.iter().filter(|a| a.path().is_ident("conv")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("other") {
+                other = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported conv attribute on a variant; expected `other`"))
+            }
+        })?;
+    }
+    Ok(other)
+}
+
+/// Describes the single field of a newtype `struct`.
+struct Newtype<'a> {
+    input: &'a DeriveInput,
+    field_ty: &'a Type,
+    field_name: Option<&'a Ident>,
+}
+
+impl<'a> Newtype<'a> {
+    /// Returns an expression which wraps the value `v` in the newtype.
+    fn construct(&self, v: &Ident) -> TokenStream2 {
+        match self.field_name {
+            Some(field) => quote!(Self { #field: #v }),
+            None => quote!(Self(#v)),
+        }
+    }
+}
+
+fn newtype<F>(input: &DeriveInput, attrs: &ConvAttrs, trait_name: &str, f: F) -> Result<TokenStream2, Error>
+where F: Fn(&Newtype, &Type) -> TokenStream2 {
+    let fields = match input.data {
+        Data::Struct(ref data) => &data.fields,
+        _ => return Err(Error::new(Span::call_site(), format!(
+            "{} can only be derived for structs with exactly one field", trait_name))),
+    };
+
+    let field = match fields.iter().collect::<Vec<_>>()[..] {
+        [field] => field,
+        _ => return Err(Error::new_spanned(fields, format!(
+            "{} can only be derived for structs with exactly one field", trait_name))),
+    };
+
+    if let Some(repr) = attrs.reprs.first() {
+        return Err(Error::new_spanned(repr,
+            "`repr` is only supported on enums; use `from` to name the source type"));
+    }
+
+    if attrs.froms.is_empty() {
+        return Err(Error::new(input.ident.span(), format!(
+            "cannot derive {} without a source type; add a `#[conv(from = \"...\")]` attribute",
+            trait_name)));
+    }
+
+    let newtype = Newtype {
+        input,
+        field_ty: &field.ty,
+        field_name: field.ident.as_ref(),
+    };
+
+    let impls = attrs.froms.iter().map(|src| f(&newtype, src));
+    Ok(quote!(#(#impls)*))
+}
+
+fn newtype_try_from(nt: &Newtype, src: &Type) -> TokenStream2 {
+    newtype_forward(nt, src, quote_spanned!(src.span()=> ::conv::TryFrom<#src>), quote!(try_from))
+}
+
+fn newtype_value_from(nt: &Newtype, src: &Type) -> TokenStream2 {
+    newtype_forward(nt, src, quote_spanned!(src.span()=> ::conv::ValueFrom<#src>), quote!(value_from))
+}
+
+fn newtype_approx_from(nt: &Newtype, src: &Type) -> TokenStream2 {
+    let name = &nt.input.ident;
+    let field_ty = nt.field_ty;
+    let v = Ident::new("v", Span::call_site());
+    let construct = nt.construct(&v);
+
+    let mut generics = nt.input.generics.clone();
+    generics.params.push(syn::parse_quote!(__ConvScheme));
+    {
+        let where_clause = generics.make_where_clause();
+        where_clause.predicates.push(syn::parse_quote!(__ConvScheme: ::conv::ApproxScheme));
+        where_clause.predicates.push(syn::parse_quote_spanned! {src.span()=>
+            #field_ty: ::conv::ApproxFrom<#src, __ConvScheme>
+        });
+    }
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = nt.input.generics.split_for_impl();
+
+    quote_spanned! {src.span()=>
+        impl #impl_generics ::conv::ApproxFrom<#src, __ConvScheme> for #name #ty_generics #where_clause {
+            type Err = <#field_ty as ::conv::ApproxFrom<#src, __ConvScheme>>::Err;
+            #[inline]
+            fn approx_from(src: #src) -> ::conv::macros::re_export::Result<Self, Self::Err> {
+                <#field_ty as ::conv::ApproxFrom<#src, __ConvScheme>>::approx_from(src)
+                    .map(|#v| #construct)
+            }
+        }
+    }
+}
+
+/// Generates an implementation of `trait_` which forwards to the newtype's field.
+fn newtype_forward(nt: &Newtype, src: &Type, trait_: TokenStream2, method: TokenStream2) -> TokenStream2 {
+    let name = &nt.input.ident;
+    let field_ty = nt.field_ty;
+    let v = Ident::new("v", Span::call_site());
+    let construct = nt.construct(&v);
+
+    let mut generics = nt.input.generics.clone();
+    generics.make_where_clause().predicates.push(syn::parse_quote_spanned! {src.span()=>
+        #field_ty: #trait_
+    });
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+    let (_, ty_generics, _) = nt.input.generics.split_for_impl();
+
+    quote_spanned! {src.span()=>
+        impl #impl_generics #trait_ for #name #ty_generics #where_clause {
+            type Err = <#field_ty as #trait_>::Err;
+            #[inline]
+            fn #method(src: #src) -> ::conv::macros::re_export::Result<Self, Self::Err> {
+                <#field_ty as #trait_>::#method(src).map(|#v| #construct)
+            }
+        }
+    }
+}
@@ -0,0 +1,106 @@
+extern crate conv;
+#[macro_use] extern crate conv_derive;
+
+use conv::*;
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[conv(repr = "u8")]
+enum Get { Up, Down, AllAround }
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[conv(repr = "u16", repr = "i64")]
+enum GottaGo {
+    GetAway,
+    Fast = 9000,
+    /// This show was stupid.
+    Faster = 9001,
+}
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(align(4), i8)]
+enum Sign { Neg = -1, Zero = 0, Pos = 1 }
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[repr(u64)]
+enum Magic {
+    Elf = 0x7f45_4c46,
+    #[conv(other)]
+    /// Anything else.
+    Unknown(u64),
+    Huge = 0xffff_ffff_ffff_fff0,
+    AfterHuge,
+}
+
+#[derive(Debug, PartialEq, ValueFrom, ApproxFrom)]
+#[conv(from = "u8", from = "i32", from = "f64")]
+struct Celsius(i16);
+
+#[derive(Debug, PartialEq, TryFrom)]
+#[conv(from = "char")]
+struct Latin1 {
+    code: u8,
+}
+
+#[derive(Debug, PartialEq, ValueFrom, ApproxFrom)]
+#[conv(from = "u8")]
+struct Tagged<T> {
+    value: T,
+}
+
+#[derive(Debug, PartialEq, ValueFrom)]
+#[conv(from = "i8")]
+struct Wrapper<T: Copy>(T) where T: PartialEq;
+
+#[test]
+fn test_try_from_enum() {
+    assert_eq!(Get::try_from(0u8), Ok(Get::Up));
+    assert_eq!(Get::try_from(1u8), Ok(Get::Down));
+    assert_eq!(Get::try_from(2u8), Ok(Get::AllAround));
+    assert_eq!(Get::try_from(3u8), Err(Unrepresentable(3u8)));
+
+    assert_eq!(GottaGo::try_from(0u16), Ok(GottaGo::GetAway));
+    assert_eq!(GottaGo::try_from(1u16), Err(Unrepresentable(1u16)));
+    assert_eq!(GottaGo::try_from(9000u16), Ok(GottaGo::Fast));
+    assert_eq!(GottaGo::try_from(9001u16), Ok(GottaGo::Faster));
+    assert_eq!(GottaGo::try_from(9002u16), Err(Unrepresentable(9002u16)));
+    assert_eq!(GottaGo::try_from(9001i64), Ok(GottaGo::Faster));
+    assert_eq!(GottaGo::try_from(-1i64), Err(Unrepresentable(-1i64)));
+
+    assert_eq!(Sign::try_from(-1i8), Ok(Sign::Neg));
+    assert_eq!(Sign::try_from(0i8), Ok(Sign::Zero));
+    assert_eq!(Sign::try_from(1i8), Ok(Sign::Pos));
+    assert_eq!(Sign::try_from(2i8), Err(Unrepresentable(2i8)));
+}
+
+#[test]
+fn test_try_from_enum_other() {
+    assert_eq!(Magic::try_from(0x7f45_4c46u64), Ok(Magic::Elf));
+    assert_eq!(Magic::try_from(0xffff_ffff_ffff_fff0u64), Ok(Magic::Huge));
+    assert_eq!(Magic::try_from(0xffff_ffff_ffff_fff1u64), Ok(Magic::AfterHuge));
+    assert_eq!(Magic::try_from(0x7f45_4c47u64), Ok(Magic::Unknown(0x7f45_4c47)));
+    assert_eq!(Magic::try_from(0u64).unwrap_ok(), Magic::Unknown(0));
+}
+
+#[test]
+fn test_newtype() {
+    assert_eq!(Celsius::value_from(200u8), Ok(Celsius(200)));
+    assert_eq!(Celsius::value_from(-40i32), Ok(Celsius(-40)));
+    assert_eq!(Celsius::value_from(40_000i32), Err(RangeError::PosOverflow(40_000)));
+    assert_eq!(Celsius::value_from(21.0f64), Ok(Celsius(21)));
+    assert_eq!(Celsius::value_from(21.5f64), Err(FloatValueError::Inexact(21.5)));
+
+    assert_eq!(Latin1::try_from('\u{ff}'), Ok(Latin1 { code: 0xff }));
+    assert_eq!(Latin1::try_from('\u{100}'), Err(PosOverflow('\u{100}')));
+
+    assert_eq!(<Celsius as ApproxFrom<_>>::approx_from(21.5f64), Ok(Celsius(21)));
+    assert_eq!(<Celsius as ApproxFrom<_, RoundToNearest>>::approx_from(21.5f64), Ok(Celsius(22)));
+    assert_eq!(<Celsius as ApproxFrom<_, Wrapping>>::approx_from(65_535i32), Ok(Celsius(-1)));
+    assert_eq!(21.5f64.approx_as_by::<Celsius, RoundToPosInf>(), Ok(Celsius(22)));
+
+    assert_eq!(Tagged::<u16>::value_from(7u8).unwrap_ok(), Tagged { value: 7u16 });
+    assert_eq!(Tagged::<i8>::value_from(200u8), Err(PosOverflow(200)));
+    assert_eq!(200u8.approx_as_by::<Tagged<i8>, Saturating>(), Ok(Tagged { value: 127 }));
+
+    assert_eq!(Wrapper::<i64>::value_from(-3i8), Ok(Wrapper(-3i64)));
+    assert_eq!(Wrapper::<u32>::value_from(-3i8), Err(NegOverflow(-3)));
+}
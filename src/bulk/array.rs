@@ -0,0 +1,40 @@
+/*!
+Array conversions, which need const generics.
+*/
+
+use {ApproxFrom, ApproxScheme, ValueFrom};
+use super::IndexedError;
+
+fn convert_array<Src, Dst, E, F, const N: usize>(src: [Src; N], mut conv: F) -> Result<[Dst; N], IndexedError<E>>
+where F: FnMut(Src) -> Result<Dst, E> {
+    let mut index = 0;
+    let mut error = None;
+    let converted = src.map(|s| {
+        let r = match error {
+            None => conv(s).map_err(|e| error = Some(IndexedError::new(index, e))).ok(),
+            Some(_) => None,
+        };
+        index += 1;
+        r
+    });
+    match error {
+        Some(e) => Err(e),
+        None => Ok(converted.map(|d| d.expect("every element converted"))),
+    }
+}
+
+/**
+Converts each element of the array `src` using `ValueFrom`.
+*/
+pub fn value_from_array<Src, Dst, const N: usize>(src: [Src; N]) -> Result<[Dst; N], IndexedError<Dst::Err>>
+where Dst: ValueFrom<Src> {
+    convert_array(src, Dst::value_from)
+}
+
+/**
+Converts each element of the array `src` using `ApproxFrom` with the scheme `Scheme`.
+*/
+pub fn approx_from_array<Src, Dst, Scheme, const N: usize>(src: [Src; N]) -> Result<[Dst; N], IndexedError<Dst::Err>>
+where Dst: ApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+    convert_array(src, Dst::approx_from)
+}
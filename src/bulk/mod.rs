@@ -0,0 +1,211 @@
+/*!
+This module provides conversions of whole slices and arrays.
+
+Each element is converted using `ValueFrom` or `ApproxFrom`.  If any element fails to convert, the error is reported along with the index of the element, as an [`IndexedError`](./struct.IndexedError.html).  The `*_or_saturate` and `*_or_invalid` variants never fail; instead, they substitute the destination type's saturated or "invalid" value for any element which could not be converted.  As with `UnwrapOrSaturate`, saturation is only available where the error is an overflow; float to integer conversions can fail on NaN, which has no saturated value.
+
+Functions which allocate a `Vec` require the `std` feature.  Array conversions require the `const_generics` feature, which requires Rust 1.55 or higher.
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+# #[cfg(feature = "std")] fn std_0() {
+use conv::RoundToNearest;
+use conv::bulk::{approx_from_slice, value_into_slice, value_into_slice_or_saturate};
+use conv::errors::{FloatError, RangeError};
+
+let samples = [0.5f64, -12.25, 40000.0];
+
+let err = approx_from_slice::<_, i16, RoundToNearest>(&samples).unwrap_err();
+assert_eq!(err.index(), 2);
+assert_eq!(*err.error(), FloatError::PosOverflow(40000.0));
+
+let mut dst = [0i16; 3];
+value_into_slice_or_saturate(&[-70000i32, 5, 70000], &mut dst);
+assert_eq!(dst, [-32768, 5, 32767]);
+
+let mut bytes = [0u8; 3];
+let err = value_into_slice(&[1i32, 2, -3], &mut bytes).unwrap_err();
+assert_eq!((err.index(), err.into_inner()), (2, RangeError::NegOverflow(-3)));
+# }
+# #[cfg(feature = "std")] std_0();
+# }
+```
+*/
+
+use std::fmt::{self, Display};
+use {ApproxFrom, ApproxScheme, Error, ValueFrom};
+use errors::{RangeErrorKind, UnwrapOrInvalid, UnwrapOrSaturate};
+use misc::{InvalidSentinel, Saturated};
+
+#[cfg(feature = "const_generics")]
+pub use self::array::{value_from_array, approx_from_array};
+
+#[cfg(feature = "const_generics")]
+mod array;
+
+#[cfg(all(feature = "simd", feature = "std"))]
+pub mod simd;
+
+/**
+An error from converting one element of a slice or array, along with that element's index.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct IndexedError<E> {
+    index: usize,
+    error: E,
+}
+
+impl<E> IndexedError<E> {
+    /// Creates a new error for the element at `index`.
+    pub fn new(index: usize, error: E) -> Self {
+        IndexedError {
+            index,
+            error,
+        }
+    }
+
+    /// Returns the index of the element which failed to convert.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the underlying error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for IndexedError<E> where E: Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        write!(fmt, "element {}: {}", self.index, self.error)
+    }
+}
+
+impl<E> Error for IndexedError<E> where E: Error {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.error.description()
+    }
+}
+
+fn convert_into<Src, Dst, E, F>(src: &[Src], dst: &mut [Dst], mut conv: F) -> Result<(), IndexedError<E>>
+where Src: Clone, F: FnMut(Src) -> Result<Dst, E> {
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    for (i, (s, d)) in src.iter().zip(dst.iter_mut()).enumerate() {
+        *d = conv(s.clone()).map_err(|e| IndexedError::new(i, e))?;
+    }
+    Ok(())
+}
+
+fn fill_into<Src, Dst, F>(src: &[Src], dst: &mut [Dst], mut conv: F)
+where Src: Clone, F: FnMut(Src) -> Dst {
+    assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = conv(s.clone());
+    }
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ValueFrom`.
+
+On failure, elements before the failing index will have been overwritten.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn value_into_slice<Src, Dst>(src: &[Src], dst: &mut [Dst]) -> Result<(), IndexedError<Dst::Err>>
+where Src: Clone, Dst: ValueFrom<Src> {
+    convert_into(src, dst, Dst::value_from)
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ApproxFrom` with the scheme `Scheme`.
+
+On failure, elements before the failing index will have been overwritten.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn approx_into_slice<Src, Dst, Scheme>(src: &[Src], dst: &mut [Dst]) -> Result<(), IndexedError<Dst::Err>>
+where Src: Clone, Dst: ApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+    convert_into(src, dst, Dst::approx_from)
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ValueFrom`, saturating any element which overflows.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn value_into_slice_or_saturate<Src, Dst>(src: &[Src], dst: &mut [Dst])
+where Src: Clone, Dst: ValueFrom<Src> + Saturated, Dst::Err: Into<RangeErrorKind> {
+    fill_into(src, dst, |s| Dst::value_from(s).unwrap_or_saturate())
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ApproxFrom` with the scheme `Scheme`, saturating any element which overflows.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn approx_into_slice_or_saturate<Src, Dst, Scheme>(src: &[Src], dst: &mut [Dst])
+where Src: Clone, Dst: ApproxFrom<Src, Scheme> + Saturated, Scheme: ApproxScheme, Dst::Err: Into<RangeErrorKind> {
+    fill_into(src, dst, |s| Dst::approx_from(s).unwrap_or_saturate())
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ValueFrom`, substituting the "invalid" sentinel for any element which fails to convert.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn value_into_slice_or_invalid<Src, Dst>(src: &[Src], dst: &mut [Dst])
+where Src: Clone, Dst: ValueFrom<Src> + InvalidSentinel {
+    fill_into(src, dst, |s| Dst::value_from(s).unwrap_or_invalid())
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ApproxFrom` with the scheme `Scheme`, substituting the "invalid" sentinel for any element which fails to convert.
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn approx_into_slice_or_invalid<Src, Dst, Scheme>(src: &[Src], dst: &mut [Dst])
+where Src: Clone, Dst: ApproxFrom<Src, Scheme> + InvalidSentinel, Scheme: ApproxScheme {
+    fill_into(src, dst, |s| Dst::approx_from(s).unwrap_or_invalid())
+}
+
+/**
+Converts each element of `src` using `ValueFrom`, collecting the results into a `Vec`.
+*/
+#[cfg(feature = "std")]
+pub fn value_from_slice<Src, Dst>(src: &[Src]) -> Result<Vec<Dst>, IndexedError<Dst::Err>>
+where Src: Clone, Dst: ValueFrom<Src> {
+    src.iter().enumerate()
+        .map(|(i, s)| Dst::value_from(s.clone()).map_err(|e| IndexedError::new(i, e)))
+        .collect()
+}
+
+/**
+Converts each element of `src` using `ApproxFrom` with the scheme `Scheme`, collecting the results into a `Vec`.
+*/
+#[cfg(feature = "std")]
+pub fn approx_from_slice<Src, Dst, Scheme>(src: &[Src]) -> Result<Vec<Dst>, IndexedError<Dst::Err>>
+where Src: Clone, Dst: ApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+    src.iter().enumerate()
+        .map(|(i, s)| Dst::approx_from(s.clone()).map_err(|e| IndexedError::new(i, e)))
+        .collect()
+}
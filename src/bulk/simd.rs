@@ -0,0 +1,437 @@
+/*!
+Vectorised slice conversions for a few common pairs of types.
+
+The functions in this module behave exactly like their counterparts in the parent module, producing bit-identical results and errors, but use SSE2 or AVX2 instructions where available.  The instruction set is detected at runtime on `x86_64`; on other targets, the scalar implementation is used.
+
+The pairs with vectorised implementations are:
+
+- `f32` → `i16`, using `DefaultApprox`, `RoundToNearest` or `Saturating`.
+- `i16` → `f32` and `u8` → `f32`, using `ValueFrom`.
+
+This module is only available with the `simd` feature enabled, which requires Rust 1.27 or higher, and the `std` feature.  Benchmarks comparing it against the scalar implementation live in the separate `conv-bench` crate, and can be run with `cargo bench` from its directory.
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+use conv::RoundToNearest;
+use conv::bulk::simd::{approx_into_slice, value_into_slice};
+
+let samples = [0.5f32, -1.5, 2.25, 1000.75];
+let mut pcm = [0i16; 4];
+approx_into_slice::<_, _, RoundToNearest>(&samples, &mut pcm).unwrap();
+assert_eq!(pcm, [1, -2, 2, 1001]);
+
+let mut floats = [0.0f32; 4];
+value_into_slice(&pcm, &mut floats).unwrap();
+assert_eq!(floats, [1.0, -2.0, 2.0, 1001.0]);
+# }
+```
+*/
+
+use {ApproxFrom, ApproxScheme, DefaultApprox, RoundToNearest, Saturating, ValueFrom};
+use super::IndexedError;
+
+/**
+This trait marks `ApproxFrom` conversions which have a vectorised slice implementation.
+*/
+pub trait SimdApproxFrom<Src, Scheme>: ApproxFrom<Src, Scheme> where Scheme: ApproxScheme {
+    /// Converts each element of `src` into the corresponding element of `dst`, as `bulk::approx_into_slice` does.
+    fn approx_into_slice(src: &[Src], dst: &mut [Self]) -> Result<(), IndexedError<Self::Err>>;
+}
+
+/**
+This trait marks `ValueFrom` conversions which have a vectorised slice implementation.
+*/
+pub trait SimdValueFrom<Src>: ValueFrom<Src> {
+    /// Converts each element of `src` into the corresponding element of `dst`, as `bulk::value_into_slice` does.
+    fn value_into_slice(src: &[Src], dst: &mut [Self]) -> Result<(), IndexedError<Self::Err>>;
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ApproxFrom` with the scheme `Scheme`.
+
+See [`bulk::approx_into_slice`](../fn.approx_into_slice.html).
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn approx_into_slice<Src, Dst, Scheme>(src: &[Src], dst: &mut [Dst]) -> Result<(), IndexedError<Dst::Err>>
+where Dst: SimdApproxFrom<Src, Scheme>, Scheme: ApproxScheme {
+    Dst::approx_into_slice(src, dst)
+}
+
+/**
+Converts each element of `src` into the corresponding element of `dst` using `ValueFrom`.
+
+See [`bulk::value_into_slice`](../fn.value_into_slice.html).
+
+# Panics
+
+Panics if the two slices have different lengths.
+*/
+pub fn value_into_slice<Src, Dst>(src: &[Src], dst: &mut [Dst]) -> Result<(), IndexedError<Dst::Err>>
+where Dst: SimdValueFrom<Src> {
+    Dst::value_into_slice(src, dst)
+}
+
+/*
+Each kernel converts whole blocks from the start of the slices, stopping early at any block containing a value it can't convert, and returns the number of elements converted.  The scalar implementation then picks up from there, which either produces the error for the offending element, or converts the remaining tail.
+*/
+macro_rules! simd_conv {
+    ($trait_:ident::$method:ident, $src:ty => $dst:ty $(, $scheme:ty)*; $kernel:ident) => {
+        impl $trait_<$src $(, $scheme)*> for $dst {
+            fn $method(src: &[$src], dst: &mut [$dst]) -> Result<(), IndexedError<Self::Err>> {
+                assert_eq!(src.len(), dst.len(), "source and destination slices have different lengths");
+                let done = kernels::$kernel(src, dst);
+                super::$method::<$src, $dst $(, $scheme)*>(&src[done..], &mut dst[done..])
+                    .map_err(|e| IndexedError::new(done + e.index(), e.into_inner()))
+            }
+        }
+    };
+}
+
+simd_conv! { SimdApproxFrom::approx_into_slice, f32 => i16, DefaultApprox; f32_to_i16_trunc }
+simd_conv! { SimdApproxFrom::approx_into_slice, f32 => i16, RoundToNearest; f32_to_i16_round }
+simd_conv! { SimdApproxFrom::approx_into_slice, f32 => i16, Saturating; f32_to_i16_sat }
+simd_conv! { SimdValueFrom::value_into_slice, i16 => f32; i16_to_f32 }
+simd_conv! { SimdValueFrom::value_into_slice, u8 => f32; u8_to_f32 }
+
+#[cfg(target_arch = "x86_64")]
+mod kernels {
+    // SSE2 is part of the `x86_64` baseline, so it never needs detecting.
+    macro_rules! dispatch {
+        ($($name:ident($src:ty => $dst:ty);)*) => {
+            $(
+                pub fn $name(src: &[$src], dst: &mut [$dst]) -> usize {
+                    unsafe {
+                        if is_x86_feature_detected!("avx2") {
+                            avx2::$name(src, dst)
+                        } else {
+                            sse2::$name(src, dst)
+                        }
+                    }
+                }
+            )*
+        };
+    }
+
+    dispatch! {
+        f32_to_i16_trunc(f32 => i16);
+        f32_to_i16_round(f32 => i16);
+        f32_to_i16_sat(f32 => i16);
+        i16_to_f32(i16 => f32);
+        u8_to_f32(u8 => f32);
+    }
+
+    /*
+    The `f32` → `i16` kernels mirror the scalar implementations:
+
+    - `DefaultApprox` truncates, and fails unless `i16::MIN <= x <= i16::MAX`.
+    - `RoundToNearest` rounds half away from zero, and fails unless the *rounded* value is in range; that is, unless `-32768.5 < x < 32767.5`.
+    - `Saturating` clamps to the range before truncating, and fails only on NaN.
+
+    All comparisons are false for NaN, so NaN always fails the range checks.
+    */
+    mod sse2 {
+        use std::arch::x86_64::*;
+
+        #[target_feature(enable = "sse2")]
+        unsafe fn round_away(x: __m128) -> __m128i {
+            // `x - trunc(x)` is exact, so we can adjust the truncated value by one when the fraction is at least a half.
+            let t = _mm_cvttps_epi32(x);
+            let d = _mm_sub_ps(x, _mm_cvtepi32_ps(t));
+            let up = _mm_castps_si128(_mm_cmpge_ps(d, _mm_set1_ps(0.5)));
+            let down = _mm_castps_si128(_mm_cmple_ps(d, _mm_set1_ps(-0.5)));
+            _mm_add_epi32(_mm_sub_epi32(t, up), down)
+        }
+
+        macro_rules! f32_to_i16 {
+            ($name:ident, |$a:ident, $b:ident| $ok:expr, |$x:ident| $conv:expr) => {
+                #[target_feature(enable = "sse2")]
+                pub unsafe fn $name(src: &[f32], dst: &mut [i16]) -> usize {
+                    let mut i = 0;
+                    while i + 8 <= src.len() {
+                        let $a = _mm_loadu_ps(src.as_ptr().add(i));
+                        let $b = _mm_loadu_ps(src.as_ptr().add(i + 4));
+                        if _mm_movemask_ps($ok) != 0xf {
+                            break;
+                        }
+                        let lo = { let $x = $a; $conv };
+                        let hi = { let $x = $b; $conv };
+                        _mm_storeu_si128(dst.as_mut_ptr().add(i) as *mut __m128i, _mm_packs_epi32(lo, hi));
+                        i += 8;
+                    }
+                    i
+                }
+            };
+        }
+
+        f32_to_i16! { f32_to_i16_trunc,
+            |a, b| {
+                let (min, max) = (_mm_set1_ps(-32768.0), _mm_set1_ps(32767.0));
+                _mm_and_ps(
+                    _mm_and_ps(_mm_cmpge_ps(a, min), _mm_cmple_ps(a, max)),
+                    _mm_and_ps(_mm_cmpge_ps(b, min), _mm_cmple_ps(b, max)))
+            },
+            |x| _mm_cvttps_epi32(x)
+        }
+
+        f32_to_i16! { f32_to_i16_round,
+            |a, b| {
+                let (min, max) = (_mm_set1_ps(-32768.5), _mm_set1_ps(32767.5));
+                _mm_and_ps(
+                    _mm_and_ps(_mm_cmpgt_ps(a, min), _mm_cmplt_ps(a, max)),
+                    _mm_and_ps(_mm_cmpgt_ps(b, min), _mm_cmplt_ps(b, max)))
+            },
+            |x| round_away(x)
+        }
+
+        f32_to_i16! { f32_to_i16_sat,
+            |a, b| _mm_and_ps(_mm_cmpord_ps(a, a), _mm_cmpord_ps(b, b)),
+            |x| _mm_cvttps_epi32(_mm_min_ps(_mm_max_ps(x, _mm_set1_ps(-32768.0)), _mm_set1_ps(32767.0)))
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn i16_to_f32(src: &[i16], dst: &mut [f32]) -> usize {
+            let mut i = 0;
+            while i + 8 <= src.len() {
+                let v = _mm_loadu_si128(src.as_ptr().add(i) as *const __m128i);
+                // Place each value in the high half of a 32-bit lane, then shift it down to sign-extend.
+                let lo = _mm_srai_epi32(_mm_unpacklo_epi16(v, v), 16);
+                let hi = _mm_srai_epi32(_mm_unpackhi_epi16(v, v), 16);
+                _mm_storeu_ps(dst.as_mut_ptr().add(i), _mm_cvtepi32_ps(lo));
+                _mm_storeu_ps(dst.as_mut_ptr().add(i + 4), _mm_cvtepi32_ps(hi));
+                i += 8;
+            }
+            i
+        }
+
+        #[target_feature(enable = "sse2")]
+        pub unsafe fn u8_to_f32(src: &[u8], dst: &mut [f32]) -> usize {
+            let mut i = 0;
+            let zero = _mm_setzero_si128();
+            while i + 16 <= src.len() {
+                let v = _mm_loadu_si128(src.as_ptr().add(i) as *const __m128i);
+                let lo = _mm_unpacklo_epi8(v, zero);
+                let hi = _mm_unpackhi_epi8(v, zero);
+                let words = [
+                    _mm_unpacklo_epi16(lo, zero), _mm_unpackhi_epi16(lo, zero),
+                    _mm_unpacklo_epi16(hi, zero), _mm_unpackhi_epi16(hi, zero),
+                ];
+                for (j, w) in words.iter().enumerate() {
+                    _mm_storeu_ps(dst.as_mut_ptr().add(i + 4 * j), _mm_cvtepi32_ps(*w));
+                }
+                i += 16;
+            }
+            i
+        }
+    }
+
+    mod avx2 {
+        use std::arch::x86_64::*;
+
+        #[target_feature(enable = "avx2")]
+        unsafe fn round_away(x: __m256) -> __m256i {
+            let t = _mm256_cvttps_epi32(x);
+            let d = _mm256_sub_ps(x, _mm256_cvtepi32_ps(t));
+            let up = _mm256_castps_si256(_mm256_cmp_ps(d, _mm256_set1_ps(0.5), _CMP_GE_OQ));
+            let down = _mm256_castps_si256(_mm256_cmp_ps(d, _mm256_set1_ps(-0.5), _CMP_LE_OQ));
+            _mm256_add_epi32(_mm256_sub_epi32(t, up), down)
+        }
+
+        macro_rules! f32_to_i16 {
+            ($name:ident, |$a:ident, $b:ident| $ok:expr, |$x:ident| $conv:expr) => {
+                #[target_feature(enable = "avx2")]
+                pub unsafe fn $name(src: &[f32], dst: &mut [i16]) -> usize {
+                    let mut i = 0;
+                    while i + 16 <= src.len() {
+                        let $a = _mm256_loadu_ps(src.as_ptr().add(i));
+                        let $b = _mm256_loadu_ps(src.as_ptr().add(i + 8));
+                        if _mm256_movemask_ps($ok) != 0xff {
+                            break;
+                        }
+                        let lo = { let $x = $a; $conv };
+                        let hi = { let $x = $b; $conv };
+                        // Packing works within 128-bit lanes, so the 64-bit quarters need to be put back in order.
+                        let packed = _mm256_permute4x64_epi64(_mm256_packs_epi32(lo, hi), 0b11_01_10_00);
+                        _mm256_storeu_si256(dst.as_mut_ptr().add(i) as *mut __m256i, packed);
+                        i += 16;
+                    }
+                    i
+                }
+            };
+        }
+
+        f32_to_i16! { f32_to_i16_trunc,
+            |a, b| {
+                let (min, max) = (_mm256_set1_ps(-32768.0), _mm256_set1_ps(32767.0));
+                _mm256_and_ps(
+                    _mm256_and_ps(_mm256_cmp_ps(a, min, _CMP_GE_OQ), _mm256_cmp_ps(a, max, _CMP_LE_OQ)),
+                    _mm256_and_ps(_mm256_cmp_ps(b, min, _CMP_GE_OQ), _mm256_cmp_ps(b, max, _CMP_LE_OQ)))
+            },
+            |x| _mm256_cvttps_epi32(x)
+        }
+
+        f32_to_i16! { f32_to_i16_round,
+            |a, b| {
+                let (min, max) = (_mm256_set1_ps(-32768.5), _mm256_set1_ps(32767.5));
+                _mm256_and_ps(
+                    _mm256_and_ps(_mm256_cmp_ps(a, min, _CMP_GT_OQ), _mm256_cmp_ps(a, max, _CMP_LT_OQ)),
+                    _mm256_and_ps(_mm256_cmp_ps(b, min, _CMP_GT_OQ), _mm256_cmp_ps(b, max, _CMP_LT_OQ)))
+            },
+            |x| round_away(x)
+        }
+
+        f32_to_i16! { f32_to_i16_sat,
+            |a, b| _mm256_and_ps(_mm256_cmp_ps(a, a, _CMP_ORD_Q), _mm256_cmp_ps(b, b, _CMP_ORD_Q)),
+            |x| _mm256_cvttps_epi32(_mm256_min_ps(_mm256_max_ps(x, _mm256_set1_ps(-32768.0)), _mm256_set1_ps(32767.0)))
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub unsafe fn i16_to_f32(src: &[i16], dst: &mut [f32]) -> usize {
+            let mut i = 0;
+            while i + 8 <= src.len() {
+                let v = _mm256_cvtepi16_epi32(_mm_loadu_si128(src.as_ptr().add(i) as *const __m128i));
+                _mm256_storeu_ps(dst.as_mut_ptr().add(i), _mm256_cvtepi32_ps(v));
+                i += 8;
+            }
+            i
+        }
+
+        #[target_feature(enable = "avx2")]
+        pub unsafe fn u8_to_f32(src: &[u8], dst: &mut [f32]) -> usize {
+            let mut i = 0;
+            while i + 8 <= src.len() {
+                let v = _mm256_cvtepu8_epi32(_mm_loadl_epi64(src.as_ptr().add(i) as *const __m128i));
+                _mm256_storeu_ps(dst.as_mut_ptr().add(i), _mm256_cvtepi32_ps(v));
+                i += 8;
+            }
+            i
+        }
+    }
+
+    /*
+    The public functions dispatch to AVX2 whenever the CPU supports it, so the SSE2 kernels are checked here directly.  Each kernel is followed by the scalar implementation on the remainder, exactly as `simd_conv!` does, and the results must be bit-identical to the scalar implementation alone.
+    */
+    #[cfg(test)]
+    mod tests {
+        use std::f32::{INFINITY, NAN, NEG_INFINITY, MIN_POSITIVE};
+        use std::fmt::Debug;
+        use {DefaultApprox, RoundToNearest, Saturating};
+        use bulk::{self, IndexedError};
+        use super::sse2;
+
+        const EDGES: &'static [f32] = &[
+            0.0, -0.0, 0.5, -0.5, 0.49999997, -0.49999997, 1.5, -1.5, 2.5, -2.5,
+            32767.0, 32767.49, 32767.5, 32767.9, 32768.0, -32768.0, -32768.49, -32768.5, -32768.9, -32769.0,
+            1e9, -1e9, INFINITY, NEG_INFINITY, NAN, MIN_POSITIVE,
+        ];
+
+        /// A small xorshift generator, so that the tests are reproducible.
+        struct Rng(u32);
+
+        impl Rng {
+            fn next(&mut self) -> u32 {
+                self.0 ^= self.0 << 13;
+                self.0 ^= self.0 >> 17;
+                self.0 ^= self.0 << 5;
+                self.0
+            }
+
+            fn sample(&mut self) -> f32 {
+                (self.next() as i32 as f32) / 65536.0
+            }
+        }
+
+        /// Random inputs of every length up to 70, followed by each edge case at every position in otherwise valid data.
+        fn inputs() -> Vec<Vec<f32>> {
+            let mut rng = Rng(0x2545_f491);
+            let mut inputs: Vec<Vec<f32>> = (0..70).map(|len| (0..len).map(|_| rng.sample()).collect()).collect();
+            for &edge in EDGES {
+                for &len in &[1, 7, 8, 16, 17, 33] {
+                    for pos in 0..len {
+                        let mut v: Vec<f32> = (0..len).map(|_| rng.sample()).collect();
+                        v[pos] = edge;
+                        inputs.push(v);
+                    }
+                }
+            }
+            inputs
+        }
+
+        fn check<S, D, E, K, F>(src: &[S], kernel: K, scalar: F)
+        where
+            S: Copy + Debug,
+            D: Copy + Default + Debug,
+            E: Debug,
+            K: Fn(&[S], &mut [D]) -> usize,
+            F: Fn(&[S], &mut [D]) -> Result<(), IndexedError<E>>,
+        {
+            let mut expected = vec![D::default(); src.len()];
+            let e = scalar(src, &mut expected);
+
+            let mut actual = vec![D::default(); src.len()];
+            let done = kernel(src, &mut actual);
+            let a = scalar(&src[done..], &mut actual[done..])
+                .map_err(|e| IndexedError::new(done + e.index(), e.into_inner()));
+
+            // NaN doesn't compare equal, and `-0.0` does, so compare the debug representations instead.
+            assert_eq!(format!("{:?}", a), format!("{:?}", e), "input: {:?}", src);
+            assert_eq!(format!("{:?}", actual), format!("{:?}", expected), "input: {:?}", src);
+        }
+
+        #[test]
+        fn test_sse2_f32_to_i16() {
+            for src in inputs() {
+                check(&src, |s, d| unsafe { sse2::f32_to_i16_trunc(s, d) },
+                    bulk::approx_into_slice::<f32, i16, DefaultApprox>);
+                check(&src, |s, d| unsafe { sse2::f32_to_i16_round(s, d) },
+                    bulk::approx_into_slice::<f32, i16, RoundToNearest>);
+                check(&src, |s, d| unsafe { sse2::f32_to_i16_sat(s, d) },
+                    bulk::approx_into_slice::<f32, i16, Saturating>);
+            }
+        }
+
+        #[test]
+        fn test_sse2_int_to_f32() {
+            let mut rng = Rng(0x1234_5678);
+            for len in 0..70 {
+                let mut ints: Vec<i16> = (0..len).map(|_| rng.next() as i16).collect();
+                if len > 4 {
+                    ints[1] = i16::min_value();
+                    ints[3] = i16::max_value();
+                }
+                check(&ints, |s, d| unsafe { sse2::i16_to_f32(s, d) }, bulk::value_into_slice::<i16, f32>);
+
+                let mut bytes: Vec<u8> = (0..len).map(|_| rng.next() as u8).collect();
+                if len > 4 {
+                    bytes[1] = 0;
+                    bytes[3] = 255;
+                }
+                check(&bytes, |s, d| unsafe { sse2::u8_to_f32(s, d) }, bulk::value_into_slice::<u8, f32>);
+            }
+        }
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod kernels {
+    macro_rules! scalar_only {
+        ($($name:ident($src:ty => $dst:ty);)*) => {
+            $(
+                pub fn $name(_: &[$src], _: &mut [$dst]) -> usize { 0 }
+            )*
+        };
+    }
+
+    scalar_only! {
+        f32_to_i16_trunc(f32 => i16);
+        f32_to_i16_round(f32 => i16);
+        f32_to_i16_sat(f32 => i16);
+        i16_to_f32(i16 => f32);
+        u8_to_f32(u8 => f32);
+    }
+}
@@ -0,0 +1,93 @@
+/*!
+This module provides adapters between the conversion traits in this crate and the `TryFrom`/`TryInto` traits in `core::convert`.
+
+It is only available with the `core_convert` feature enabled, which requires Rust 1.34 or higher.
+
+Because of the blanket implementations on both sides (notably `impl<T> TryFrom<T> for T`), it is not possible to implement either set of traits in terms of the other for all types.  Instead, conversions are routed through wrapper types:
+
+- [`FromStd<T, Src>`](./struct.FromStd.html) implements `conv::TryFrom<Src>` wherever `T` implements `core::convert::TryFrom<Src>`.
+- [`FromConv<T>`](./struct.FromConv.html) implements `core::convert::TryFrom<Conv<Src>>` wherever `T` implements `conv::TryFrom<Src>`, with [`Conv<Src>`](./struct.Conv.html) marking the input.
+
+For simply *calling* the standard traits without fighting name resolution, see [`ConvUtil::try_as_std`](../trait.ConvUtil.html#method.try_as_std).
+
+# Example
+
+```
+#[macro_use] extern crate conv;
+
+use conv::TryFrom;
+use conv::compat::{Conv, FromConv, FromStd};
+
+#[derive(Debug, PartialEq)]
+enum Colours { Red, Green, Blue }
+
+TryFrom! { (u8) enum Colours { Red, Green, Blue } }
+
+fn main() {
+    // A `core::convert::TryFrom` conversion, used through `conv::TryFrom`.
+    let v = FromStd::<u8, _>::try_from(200i32).unwrap();
+    assert_eq!(v.into_inner(), 200u8);
+    assert!(FromStd::<u8, _>::try_from(300i32).is_err());
+
+    // A `conv::TryFrom` conversion, used through `core::convert::TryFrom`.
+    let v = <FromConv<Colours> as std::convert::TryFrom<_>>::try_from(Conv(2u8)).unwrap();
+    assert_eq!(v.into_inner(), Colours::Blue);
+}
+```
+*/
+
+use std::convert;
+use std::marker::PhantomData;
+use ::Error;
+
+/**
+Adapts a `core::convert::TryFrom<Src>` implementation on `T` into a `conv::TryFrom<Src>` implementation.
+
+The `Src` parameter exists only to keep this implementation from overlapping with `conv::TryFrom<T> for T`.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FromStd<T, Src>(T, PhantomData<fn(Src)>);
+
+impl<T, Src> FromStd<T, Src> {
+    /// Returns the converted value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, Src> ::TryFrom<Src> for FromStd<T, Src>
+where T: convert::TryFrom<Src>, T::Error: Error {
+    type Err = T::Error;
+    fn try_from(src: Src) -> Result<Self, Self::Err> {
+        convert::TryFrom::try_from(src).map(|v| FromStd(v, PhantomData))
+    }
+}
+
+/**
+Marks a value as the input to a `conv::TryFrom` conversion performed through `core::convert::TryFrom`.
+
+See [`FromConv`](./struct.FromConv.html).
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct Conv<T>(pub T);
+
+/**
+Adapts a `conv::TryFrom<Src>` implementation on `T` into a `core::convert::TryFrom<Conv<Src>>` implementation.
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug)]
+pub struct FromConv<T>(pub T);
+
+impl<T> FromConv<T> {
+    /// Returns the converted value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, Src> convert::TryFrom<Conv<Src>> for FromConv<T>
+where T: ::TryFrom<Src> {
+    type Error = T::Err;
+    fn try_from(src: Conv<Src>) -> Result<Self, Self::Error> {
+        ::TryFrom::try_from(src.0).map(FromConv)
+    }
+}
@@ -0,0 +1,383 @@
+/*!
+This module provides an opt-in layer for attaching the source and destination types to conversion errors.
+
+It is only available with the `context` feature enabled, which requires Rust 1.38 or higher.
+
+The error types in the [`errors`](../errors/index.html) module deliberately carry only the input value, and describe themselves in general terms.  This is often not enough to diagnose a failure after the fact.  Calling [`context`](./trait.ResultContext.html#tymethod.context) on the result of a conversion wraps any error in a [`ConvError`](./struct.ConvError.html), which records the name of the destination type and displays as, for example:
+
+```text
+300i32 → u8: positive overflow (max 255)
+```
+
+The destination type, and the type of the value stored in the error, must implement [`ContextType`](./trait.ContextType.html), which determines how values are displayed and which limit (if any) is shown for an overflow.  It is implemented for the primitive types, and for the other types this crate converts between; other types can implement it with an empty `impl`.
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+use conv::prelude::*;
+use conv::context::ResultContext;
+
+let err = 300i32.value_as::<u8>().context().unwrap_err();
+assert_eq!(err.to_string(), "300i32 → u8: positive overflow (max 255)");
+
+let err = (-1i8).approx_as::<u32>().context().unwrap_err();
+assert_eq!(err.to_string(), "-1i8 → u32: negative overflow (min 0)");
+
+#[derive(Debug)]
+struct Even(u8);
+
+impl conv::context::ContextType for Even {}
+
+let err = Err::<Even, _>(conv::Unrepresentable(3u8)).context().unwrap_err();
+assert!(err.to_string().ends_with("Even: unrepresentable"));
+# }
+```
+*/
+
+use std::any::type_name;
+use std::fmt::{self, Display};
+use ::Error;
+use errors::{
+    NoError, GeneralError, GeneralErrorKind, Unrepresentable, Inexact,
+    NegOverflow, PosOverflow,
+    FloatError, FloatErrorKind, FloatValueError, RangeError, RangeErrorKind,
+};
+
+/**
+This trait describes a conversion error in enough detail to render it with context.
+
+It is implemented for all error types in the [`errors`](../errors/index.html) module.
+*/
+pub trait Contextual {
+    /// The type of the input value stored in the error.  Errors which do not store the input use `NoError`.
+    type Src;
+
+    /// Returns the input value stored in the error, if any.
+    fn src(&self) -> Option<&Self::Src>;
+
+    /// Returns the general kind of the error.
+    fn kind(&self) -> GeneralErrorKind;
+
+    /// Returns a short description of why the conversion failed.
+    fn reason(&self) -> &'static str;
+}
+
+impl Contextual for NoError {
+    type Src = NoError;
+    fn src(&self) -> Option<&NoError> { match *self {} }
+    fn kind(&self) -> GeneralErrorKind { match *self {} }
+    fn reason(&self) -> &'static str { match *self {} }
+}
+
+macro_rules! contextual_struct {
+    ($($name:ident => $reason:expr),* $(,)*) => {
+        $(
+            impl<T> Contextual for $name<T> {
+                type Src = T;
+                fn src(&self) -> Option<&T> { Some(&self.0) }
+                fn kind(&self) -> GeneralErrorKind { GeneralErrorKind::$name }
+                fn reason(&self) -> &'static str { $reason }
+            }
+        )*
+    };
+}
+
+contextual_struct! {
+    NegOverflow => "negative overflow",
+    PosOverflow => "positive overflow",
+    Unrepresentable => "unrepresentable",
+}
+
+impl<T> Contextual for Inexact<T> {
+    type Src = T;
+    fn src(&self) -> Option<&T> { Some(&self.0) }
+    fn kind(&self) -> GeneralErrorKind { GeneralErrorKind::Unrepresentable }
+    fn reason(&self) -> &'static str { "inexact" }
+}
+
+impl<T> Contextual for GeneralError<T> {
+    type Src = T;
+    fn src(&self) -> Option<&T> {
+        use self::GeneralError as E;
+        match *self {
+            E::NegOverflow(ref v) | E::PosOverflow(ref v)
+            | E::Unrepresentable(ref v) | E::NotANumber(ref v) => Some(v),
+        }
+    }
+    fn kind(&self) -> GeneralErrorKind {
+        use self::GeneralError as E;
+        use self::GeneralErrorKind as K;
+        match *self {
+            E::NegOverflow(..) => K::NegOverflow,
+            E::PosOverflow(..) => K::PosOverflow,
+            E::Unrepresentable(..) => K::Unrepresentable,
+            E::NotANumber(..) => K::NotANumber,
+        }
+    }
+    fn reason(&self) -> &'static str {
+        self.kind().reason()
+    }
+}
+
+impl Contextual for GeneralErrorKind {
+    type Src = NoError;
+    fn src(&self) -> Option<&NoError> { None }
+    fn kind(&self) -> GeneralErrorKind { *self }
+    fn reason(&self) -> &'static str {
+        use self::GeneralErrorKind as K;
+        match *self {
+            K::NegOverflow => "negative overflow",
+            K::PosOverflow => "positive overflow",
+            K::Unrepresentable => "unrepresentable",
+            K::NotANumber => "not a number",
+        }
+    }
+}
+
+impl<T> Contextual for FloatError<T> {
+    type Src = T;
+    fn src(&self) -> Option<&T> {
+        use self::FloatError as E;
+        match *self {
+            E::NegOverflow(ref v) | E::PosOverflow(ref v) | E::NotANumber(ref v) => Some(v),
+        }
+    }
+    fn kind(&self) -> GeneralErrorKind {
+        use self::FloatError as E;
+        use self::GeneralErrorKind as K;
+        match *self {
+            E::NegOverflow(..) => K::NegOverflow,
+            E::PosOverflow(..) => K::PosOverflow,
+            E::NotANumber(..) => K::NotANumber,
+        }
+    }
+    fn reason(&self) -> &'static str {
+        self.kind().reason()
+    }
+}
+
+impl<T> Contextual for FloatValueError<T> {
+    type Src = T;
+    fn src(&self) -> Option<&T> {
+        use self::FloatValueError as E;
+        match *self {
+            E::NegOverflow(ref v) | E::PosOverflow(ref v)
+            | E::NotANumber(ref v) | E::Inexact(ref v) => Some(v),
+        }
+    }
+    fn kind(&self) -> GeneralErrorKind {
+        use self::FloatValueError as E;
+        use self::GeneralErrorKind as K;
+        match *self {
+            E::NegOverflow(..) => K::NegOverflow,
+            E::PosOverflow(..) => K::PosOverflow,
+            E::NotANumber(..) => K::NotANumber,
+            E::Inexact(..) => K::Unrepresentable,
+        }
+    }
+    fn reason(&self) -> &'static str {
+        match *self {
+            FloatValueError::Inexact(..) => "inexact",
+            _ => self.kind().reason(),
+        }
+    }
+}
+
+impl<T> Contextual for RangeError<T> {
+    type Src = T;
+    fn src(&self) -> Option<&T> {
+        match *self {
+            RangeError::NegOverflow(ref v) | RangeError::PosOverflow(ref v) => Some(v),
+        }
+    }
+    fn kind(&self) -> GeneralErrorKind {
+        match *self {
+            RangeError::NegOverflow(..) => GeneralErrorKind::NegOverflow,
+            RangeError::PosOverflow(..) => GeneralErrorKind::PosOverflow,
+        }
+    }
+    fn reason(&self) -> &'static str {
+        self.kind().reason()
+    }
+}
+
+impl Contextual for FloatErrorKind {
+    type Src = NoError;
+    fn src(&self) -> Option<&NoError> { None }
+    fn kind(&self) -> GeneralErrorKind { (*self).into() }
+    fn reason(&self) -> &'static str { self.kind().reason() }
+}
+
+impl Contextual for RangeErrorKind {
+    type Src = NoError;
+    fn src(&self) -> Option<&NoError> { None }
+    fn kind(&self) -> GeneralErrorKind { (*self).into() }
+    fn reason(&self) -> &'static str { self.kind().reason() }
+}
+
+/**
+This trait describes how a type is shown in a [`ConvError`](./struct.ConvError.html).
+
+All of its items have defaults, which display values in the form `value (type)`, and show no limits.
+*/
+pub trait ContextType {
+    /// Whether values are displayed with the name of the type as a suffix, as with `300i32`, rather than in parentheses.
+    const SUFFIXED: bool = false;
+
+    /// The minimum value of the type, as shown after a negative overflow.
+    const MIN: Option<&'static str> = None;
+
+    /// The maximum value of the type, as shown after a positive overflow.
+    const MAX: Option<&'static str> = None;
+}
+
+macro_rules! context_type {
+    ($($ty:ty => [$min:expr, $max:expr]),* $(,)*) => {
+        $(
+            impl ContextType for $ty {
+                const SUFFIXED: bool = true;
+                const MIN: Option<&'static str> = Some($min);
+                const MAX: Option<&'static str> = Some($max);
+            }
+        )*
+    };
+}
+
+context_type! {
+    i8 => ["-128", "127"],
+    i16 => ["-32768", "32767"],
+    i32 => ["-2147483648", "2147483647"],
+    i64 => ["-9223372036854775808", "9223372036854775807"],
+    i128 => ["-170141183460469231731687303715884105728", "170141183460469231731687303715884105727"],
+    u8 => ["0", "255"],
+    u16 => ["0", "65535"],
+    u32 => ["0", "4294967295"],
+    u64 => ["0", "18446744073709551615"],
+    u128 => ["0", "340282366920938463463374607431768211455"],
+    f32 => ["-3.4028235e38", "3.4028235e38"],
+    f64 => ["-1.7976931348623157e308", "1.7976931348623157e308"],
+}
+
+#[cfg(target_pointer_width = "16")]
+context_type! { isize => ["-32768", "32767"], usize => ["0", "65535"] }
+
+#[cfg(target_pointer_width = "32")]
+context_type! { isize => ["-2147483648", "2147483647"], usize => ["0", "4294967295"] }
+
+#[cfg(target_pointer_width = "64")]
+context_type! {
+    isize => ["-9223372036854775808", "9223372036854775807"],
+    usize => ["0", "18446744073709551615"],
+}
+
+impl ContextType for NoError {}
+impl ContextType for bool {}
+impl ContextType for char {}
+#[cfg(feature = "std")]
+impl ContextType for ::std::string::String {}
+impl ContextType for ::std::time::Duration {}
+impl<T> ContextType for ::std::num::Wrapping<T> {}
+
+#[cfg(feature = "num_saturating")]
+impl<T> ContextType for ::std::num::Saturating<T> {}
+
+#[cfg(feature = "half")]
+impl ContextType for ::half::f16 {}
+
+#[cfg(feature = "half")]
+impl ContextType for ::half::bf16 {}
+
+#[cfg(feature = "nonzero")]
+item_for_each! {
+    (NonZeroI8), (NonZeroI16), (NonZeroI32), (NonZeroI64), (NonZeroI128), (NonZeroIsize),
+    (NonZeroU8), (NonZeroU16), (NonZeroU32), (NonZeroU64), (NonZeroU128), (NonZeroUsize) => {
+        ($nz:ident) => {
+            impl ContextType for ::std::num::$nz {}
+        };
+    }
+}
+
+/**
+A conversion error, along with the name of the type being converted to.
+
+The name of the source type is taken from the error's payload.  Values of this type are usually created using [`ResultContext::context`](./trait.ResultContext.html#tymethod.context).
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug)]
+pub struct ConvError<E> {
+    error: E,
+    dst: &'static str,
+    dst_min: Option<&'static str>,
+    dst_max: Option<&'static str>,
+}
+
+impl<E> ConvError<E> {
+    /// Wraps an error from a conversion into `Dst`.
+    pub fn new<Dst>(error: E) -> Self where Dst: ContextType {
+        ConvError {
+            error,
+            dst: type_name::<Dst>(),
+            dst_min: Dst::MIN,
+            dst_max: Dst::MAX,
+        }
+    }
+
+    /// Returns a reference to the underlying error.
+    pub fn error(&self) -> &E {
+        &self.error
+    }
+
+    /// Returns the name of the destination type.
+    pub fn dst_type(&self) -> &'static str {
+        self.dst
+    }
+
+    /// Returns the underlying error.
+    pub fn into_inner(self) -> E {
+        self.error
+    }
+}
+
+impl<E> Display for ConvError<E> where E: Contextual, E::Src: ContextType + Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        let src_ty = type_name::<E::Src>();
+        match self.error.src() {
+            Some(v) if E::Src::SUFFIXED => write!(fmt, "{}{} → ", v, src_ty)?,
+            Some(v) => write!(fmt, "{} ({}) → ", v, src_ty)?,
+            None => write!(fmt, "conversion to ")?,
+        }
+        write!(fmt, "{}: {}", self.dst, self.error.reason())?;
+        match (self.error.kind(), self.dst_min, self.dst_max) {
+            (GeneralErrorKind::NegOverflow, Some(min), _) => write!(fmt, " (min {})", min),
+            (GeneralErrorKind::PosOverflow, _, Some(max)) => write!(fmt, " (max {})", max),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl<E> Error for ConvError<E> where E: Contextual + Error + 'static, E::Src: ContextType + Display {
+    fn description(&self) -> &str {
+        self.error.reason()
+    }
+
+    #[cfg(feature = "std")]
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/**
+This extension trait attaches context to the errors of conversion results.
+*/
+pub trait ResultContext<T, E> {
+    /// Wraps any error in a `ConvError` which records the destination type `T`.
+    fn context(self) -> Result<T, ConvError<E>>;
+}
+
+impl<T, E> ResultContext<T, E> for Result<T, E> where T: ContextType, E: Contextual {
+    #[inline]
+    fn context(self) -> Result<T, ConvError<E>> {
+        self.map_err(ConvError::new::<T>)
+    }
+}
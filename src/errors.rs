@@ -42,6 +42,14 @@ macro_rules! DummyDebug {
                 write!(fmt, concat!(stringify!($name), "::{}(..)"), msg)
             }
         }
+
+        impl<$t> Debug for Verbose<$name<$t>> where $t: Debug {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                match self.0 {
+                    $($name::$vname(ref v) => fmt.debug_tuple(concat!(stringify!($name), "::", stringify!($vname))).field(v).finish(),)+
+                }
+            }
+        }
     };
 
     (
@@ -52,6 +60,12 @@ macro_rules! DummyDebug {
                 write!(fmt, concat!(stringify!($name), "(..)"))
             }
         }
+
+        impl<$t> Debug for Verbose<$name<$t>> where $t: Debug {
+            fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+                fmt.debug_tuple(stringify!($name)).field(&(self.0).0).finish()
+            }
+        }
     };
 }
 
@@ -237,11 +251,13 @@ custom_derive!{
             NegOverflow => "conversion resulted in negative overflow",
             PosOverflow => "conversion resulted in positive overflow",
             Unrepresentable => "could not convert unrepresentable value",
+            NotANumber => "conversion target does not support not-a-number",
         ),
         FromName(Unrepresentable),
         FromName(NegOverflow),
         FromName(PosOverflow),
-        FromRemap(RangeError(NegOverflow, PosOverflow))
+        FromRemap(RangeError(NegOverflow, PosOverflow)),
+        FromRemap(FloatError(NegOverflow, PosOverflow, NotANumber))
     )]
     pub enum GeneralError<T> {
         /// Input was too negative for the target type.
@@ -252,19 +268,16 @@ custom_derive!{
 
         /// Input was not representable in the target type.
         Unrepresentable(T),
+
+        /// Input was not-a-number, which the target type could not represent.
+        NotANumber(T),
     }
 }
 
-impl<T> From<FloatError<T>> for GeneralError<T> {
+impl<T> From<Inexact<T>> for GeneralError<T> {
     #[inline]
-    fn from(e: FloatError<T>) -> GeneralError<T> {
-        use self::FloatError as F;
-        use self::GeneralError as G;
-        match e {
-            F::NegOverflow(v) => G::NegOverflow(v),
-            F::PosOverflow(v) => G::PosOverflow(v),
-            F::NotANumber(v) => G::Unrepresentable(v),
-        }
+    fn from(e: Inexact<T>) -> GeneralError<T> {
+        GeneralError::Unrepresentable(e.into_inner())
     }
 }
 
@@ -281,13 +294,16 @@ custom_derive! {
             NegOverflow => "conversion resulted in negative overflow",
             PosOverflow => "conversion resulted in positive overflow",
             Unrepresentable => "could not convert unrepresentable value",
+            NotANumber => "conversion target does not support not-a-number",
         ),
         FromName(Unrepresentable<T>),
         FromName(NegOverflow<T>),
         FromName(PosOverflow<T>),
         FromRemap(RangeErrorKind(NegOverflow, PosOverflow)),
         FromRemap(RangeError<T>(NegOverflow, PosOverflow)),
-        FromRemap(GeneralError<T>(NegOverflow, PosOverflow, Unrepresentable))
+        FromRemap(FloatErrorKind(NegOverflow, PosOverflow, NotANumber)),
+        FromRemap(FloatError<T>(NegOverflow, PosOverflow, NotANumber)),
+        FromRemap(GeneralError<T>(NegOverflow, PosOverflow, Unrepresentable, NotANumber))
     )]
     pub enum GeneralErrorKind {
         /// Input was too negative for the target type.
@@ -298,22 +314,119 @@ custom_derive! {
 
         /// Input was not representable in the target type.
         Unrepresentable,
+
+        /// Input was not-a-number, which the target type could not represent.
+        NotANumber,
     }
 }
 
-impl<T> From<FloatError<T>> for GeneralErrorKind {
+impl<T> From<Inexact<T>> for GeneralErrorKind {
     #[inline]
-    fn from(e: FloatError<T>) -> GeneralErrorKind {
-        use self::FloatError as F;
+    fn from(_: Inexact<T>) -> GeneralErrorKind {
+        GeneralErrorKind::Unrepresentable
+    }
+}
+
+impl<T> From<FloatValueError<T>> for GeneralError<T> {
+    #[inline]
+    fn from(e: FloatValueError<T>) -> GeneralError<T> {
+        use self::FloatValueError as F;
+        use self::GeneralError as G;
+        match e {
+            F::NegOverflow(v) => G::NegOverflow(v),
+            F::PosOverflow(v) => G::PosOverflow(v),
+            F::NotANumber(v) => G::NotANumber(v),
+            F::Inexact(v) => G::Unrepresentable(v),
+        }
+    }
+}
+
+impl<T> From<FloatValueError<T>> for GeneralErrorKind {
+    #[inline]
+    fn from(e: FloatValueError<T>) -> GeneralErrorKind {
+        use self::FloatValueError as F;
         use self::GeneralErrorKind as G;
         match e {
             F::NegOverflow(..) => G::NegOverflow,
             F::PosOverflow(..) => G::PosOverflow,
-            F::NotANumber(..) => G::Unrepresentable,
+            F::NotANumber(..) => G::NotANumber,
+            F::Inexact(..) => G::Unrepresentable,
         }
     }
 }
 
+/**
+Wraps an error so that its `Debug` output includes the input value.
+
+The error types in this module must implement `Debug` regardless of their payload type, and so their `Debug` output hides the input value (*e.g.* `RangeError::PosOverflow(..)`).  Wrapping one of these errors in `Verbose` provides a `Debug` implementation that shows the input value (*e.g.* `RangeError::PosOverflow(300)`), provided it also implements `Debug`.  All other traits are forwarded to the wrapped error.
+
+This is most useful when an error is about to be unwrapped or reported:
+
+```
+# extern crate conv;
+# use conv::prelude::*;
+# use conv::errors::Verbose;
+# fn main() {
+let err = 300i32.value_as::<u8>().map_err(Verbose).unwrap_err();
+assert_eq!(format!("{:?}", err), "RangeError::PosOverflow(300)");
+# }
+```
+*/
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Verbose<E>(pub E);
+
+impl<E> Verbose<E> {
+    /// Returns the wrapped error.
+    #[inline]
+    pub fn into_inner(self) -> E {
+        self.0
+    }
+}
+
+impl<E> From<E> for Verbose<E> {
+    #[inline]
+    fn from(e: E) -> Self {
+        Verbose(e)
+    }
+}
+
+impl<E> Display for Verbose<E> where E: Display {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Display::fmt(&self.0, fmt)
+    }
+}
+
+impl<E> Error for Verbose<E> where E: Error, Verbose<E>: Debug + Any {
+    #[allow(deprecated)]
+    fn description(&self) -> &str {
+        self.0.description()
+    }
+}
+
+impl Debug for Verbose<NoError> {
+    fn fmt(&self, _: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        match self.0 {}
+    }
+}
+
+impl Debug for Verbose<GeneralErrorKind> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(&self.0, fmt)
+    }
+}
+
+impl Debug for Verbose<FloatErrorKind> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(&self.0, fmt)
+    }
+}
+
+impl Debug for Verbose<RangeErrorKind> {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error> {
+        Debug::fmt(&self.0, fmt)
+    }
+}
+
 /**
 Indicates that it is not possible for the conversion to fail.
 
@@ -344,6 +457,16 @@ custom_derive! {
     pub struct Unrepresentable<T>(pub T);
 }
 
+custom_derive! {
+    /// Indicates that the conversion failed because the value could not be represented exactly.
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd,
+        IntoInner, DummyDebug, FromNoError,
+        Desc("could not convert value exactly")
+    )]
+    pub struct Inexact<T>(pub T);
+}
+
 custom_derive! {
     /// Indicates that the conversion failed due to a negative overflow.
     #[derive(
@@ -392,6 +515,74 @@ custom_derive! {
     }
 }
 
+custom_derive! {
+    /**
+    Indicates that a conversion from a floating point type failed.
+
+    This is a variant of `FloatError` that does not retain the input value which caused the error.
+    */
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Debug,
+        FromNoError,
+        EnumDesc(
+            NegOverflow => "conversion resulted in negative overflow",
+            PosOverflow => "conversion resulted in positive overflow",
+            NotANumber => "conversion target does not support not-a-number",
+        ),
+        FromName(NegOverflow<T>),
+        FromName(PosOverflow<T>),
+        FromRemap(RangeErrorKind(NegOverflow, PosOverflow)),
+        FromRemap(RangeError<T>(NegOverflow, PosOverflow)),
+        FromRemap(FloatError<T>(NegOverflow, PosOverflow, NotANumber))
+    )]
+    pub enum FloatErrorKind {
+        /// Input was too negative for the target type.
+        NegOverflow,
+
+        /// Input was too positive for the target type.
+        PosOverflow,
+
+        /// Input was not-a-number, which the target type could not represent.
+        NotANumber,
+    }
+}
+
+custom_derive! {
+    /**
+    Indicates that an exact conversion from a floating point type failed.
+
+    This differs from `FloatError` in that it can also indicate that the input had a fractional part, which the target type could not represent.
+    */
+    #[derive(
+        Copy, Clone, Eq, PartialEq, Ord, PartialOrd,
+        IntoInner, DummyDebug, FromNoError,
+        EnumDesc(
+            NegOverflow => "conversion resulted in negative overflow",
+            PosOverflow => "conversion resulted in positive overflow",
+            NotANumber => "conversion target does not support not-a-number",
+            Inexact => "could not convert value exactly",
+        ),
+        FromName(NegOverflow),
+        FromName(PosOverflow),
+        FromName(Inexact),
+        FromRemap(RangeError(NegOverflow, PosOverflow)),
+        FromRemap(FloatError(NegOverflow, PosOverflow, NotANumber))
+    )]
+    pub enum FloatValueError<T> {
+        /// Input was too negative for the target type.
+        NegOverflow(T),
+
+        /// Input was too positive for the target type.
+        PosOverflow(T),
+
+        /// Input was not-a-number, which the target type could not represent.
+        NotANumber(T),
+
+        /// Input had a fractional part, which the target type could not represent.
+        Inexact(T),
+    }
+}
+
 custom_derive! {
     /**
     Indicates that a conversion failed due to a range error.
@@ -504,6 +695,65 @@ where T: Saturated {
     }
 }
 
+impl<T, U> Saturate for Result<T, NegOverflow<U>>
+where T: Saturated {
+    type Output = Result<T, NoError>;
+
+    #[inline]
+    fn saturate(self) -> Self::Output {
+        match self {
+            Ok(v) => Ok(v),
+            Err(NegOverflow(_)) => Ok(T::saturated_min())
+        }
+    }
+}
+
+impl<T, U> Saturate for Result<T, PosOverflow<U>>
+where T: Saturated {
+    type Output = Result<T, NoError>;
+
+    #[inline]
+    fn saturate(self) -> Self::Output {
+        match self {
+            Ok(v) => Ok(v),
+            Err(PosOverflow(_)) => Ok(T::saturated_max())
+        }
+    }
+}
+
+impl<T, U> Saturate for Result<T, GeneralError<U>>
+where T: Saturated {
+    type Output = Result<T, Unrepresentable<U>>;
+
+    #[inline]
+    fn saturate(self) -> Self::Output {
+        use self::GeneralError as E;
+        match self {
+            Ok(v) => Ok(v),
+            Err(E::NegOverflow(_)) => Ok(T::saturated_min()),
+            Err(E::PosOverflow(_)) => Ok(T::saturated_max()),
+            Err(E::Unrepresentable(v)) | Err(E::NotANumber(v)) => Err(Unrepresentable(v))
+        }
+    }
+}
+
+// Any residual error is passed through unchanged.
+impl<T> Saturate for Result<T, GeneralErrorKind>
+where T: Saturated {
+    type Output = Result<T, GeneralErrorKind>;
+
+    #[inline]
+    fn saturate(self) -> Self::Output {
+        use self::GeneralErrorKind::*;
+        match self {
+            Ok(v) => Ok(v),
+            Err(NegOverflow) => Ok(T::saturated_min()),
+            Err(PosOverflow) => Ok(T::saturated_max()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
 /**
 Safely unwrap a `Result` that cannot contain an error.
 */
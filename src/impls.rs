@@ -6,6 +6,20 @@ macro_rules! min_of {
     ($name:ident) => { ::std::$name::MIN };
 }
 
+#[cfg(feature = "std")]
+macro_rules! round_ties_even {
+    ($e:expr) => {
+        {
+            let v = $e;
+            if (v - v.trunc()).abs() == 0.5 {
+                (v / 2.0).round() * 2.0
+            } else {
+                v.round()
+            }
+        }
+    };
+}
+
 macro_rules! approx_blind {
     (($($attrs:tt)*), $src:ty, $dst:ty, $scheme:ty) => {
         as_item! {
@@ -81,6 +95,129 @@ macro_rules! approx_dmin_to_dmax {
     }
 }
 
+macro_rules! approx_sat_z_to_dmax {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if !(0 <= src) {
+                        return Ok(0);
+                    }
+                    if !(src <= max_of!($dst) as $src) {
+                        return Ok(max_of!($dst));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_sat_to_dmax {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if !(src <= max_of!($dst) as $src) {
+                        return Ok(max_of!($dst));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
+macro_rules! approx_sat_dmin_to_dmax {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if !(min_of!($dst) as $src <= src) {
+                        return Ok(min_of!($dst));
+                    }
+                    if !(src <= max_of!($dst) as $src) {
+                        return Ok(max_of!($dst));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    }
+}
+
+macro_rules! approx_sat_z_up {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if !(0 <= src) {
+                        return Ok(0);
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! approx_sat_to_finite {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::NoError;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    let approx = src as $dst;
+                    if !approx.is_finite() {
+                        return Ok(max_of!($dst));
+                    }
+                    Ok(approx)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! approx_sat_range_no_nan {
+    (($($attrs:tt)*), $src:ty, $dst:ident, [$min:expr, $max:expr], $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::Unrepresentable<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    if src.is_nan() {
+                        return Err(::errors::Unrepresentable(src));
+                    }
+                    if !($min <= src) {
+                        return Ok(min_of!($dst));
+                    }
+                    if !(src <= $max) {
+                        return Ok(max_of!($dst));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! approx_z_up {
     (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
         as_item! {
@@ -99,6 +236,26 @@ macro_rules! approx_z_up {
     };
 }
 
+#[cfg(feature = "std")]
+macro_rules! approx_to_finite {
+    (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
+        as_item! {
+            $($attrs)*
+            impl ::ApproxFrom<$src, $scheme> for $dst {
+                type Err = ::errors::PosOverflow<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$dst, Self::Err> {
+                    let approx = src as $dst;
+                    if !approx.is_finite() {
+                        return Err(::errors::PosOverflow(src));
+                    }
+                    Ok(approx)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! approx_dmin_to_dmax_no_nan {
     (($($attrs:tt)*), $src:ty, $dst:ident, $scheme:ty) => {
         approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, $scheme, approx: |s| s }
@@ -142,6 +299,56 @@ macro_rules! approx_range_no_nan {
     };
 }
 
+#[cfg(feature = "std")]
+macro_rules! value_range_no_nan {
+    (($($attrs:tt)*), $src:ty, $dst:ident, [$min:expr, $max:expr]) => {
+        as_item! {
+            $($attrs)*
+            impl ::ValueFrom<$src> for $dst {
+                type Err = ::errors::FloatValueError<$src>;
+                #[inline]
+                fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                    if src.is_nan() {
+                        return Err(::errors::FloatValueError::NotANumber(src));
+                    }
+                    if !($min <= src) {
+                        return Err(::errors::FloatValueError::NegOverflow(src));
+                    }
+                    if !(src <= $max) {
+                        return Err(::errors::FloatValueError::PosOverflow(src));
+                    }
+                    if src.fract() != 0.0 {
+                        return Err(::errors::FloatValueError::Inexact(src));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+macro_rules! value_int_to_float {
+    (($($attrs:tt)*), $src:ty, $dst:ident, abs: |$s:ident| $abs:expr) => {
+        as_item! {
+            $($attrs)*
+            impl ::ValueFrom<$src> for $dst {
+                type Err = ::errors::Inexact<$src>;
+                #[inline]
+                fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                    // An integer is exactly representable if its odd part fits in the significand.  No integer type is large enough to exceed the exponent range.
+                    let $s = src;
+                    let mag: u128 = $abs;
+                    if mag != 0 && (mag >> mag.trailing_zeros()) >> ::std::$dst::MANTISSA_DIGITS != 0 {
+                        return Err(::errors::Inexact(src));
+                    }
+                    Ok(src as $dst)
+                }
+            }
+        }
+    };
+}
+
 macro_rules! num_conv {
     (@ $src:ty=> $(,)*) => {};
 
@@ -159,7 +366,9 @@ macro_rules! num_conv {
     (@ $src:ty=> w+  $($tail:tt)*) => { num_conv! { @ $src=> () w+  $($tail)* } };
     (@ $src:ty=> w   $($tail:tt)*) => { num_conv! { @ $src=> () w   $($tail)* } };
     (@ $src:ty=> aW  $($tail:tt)*) => { num_conv! { @ $src=> () aW  $($tail)* } };
+    (@ $src:ty=> nf+- $($tail:tt)*) => { num_conv! { @ $src=> () nf+- $($tail)* } };
     (@ $src:ty=> nf  $($tail:tt)*) => { num_conv! { @ $src=> () nf  $($tail)* } };
+    (@ $src:ty=> nfo $($tail:tt)*) => { num_conv! { @ $src=> () nfo $($tail)* } };
     (@ $src:ty=> fan $($tail:tt)*) => { num_conv! { @ $src=> () fan $($tail)* } };
 
     // Exact conversion
@@ -167,6 +376,7 @@ macro_rules! num_conv {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_blind! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -185,6 +395,7 @@ macro_rules! num_conv {
         as_item! {
             approx_z_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_sat_z_to_dmax! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -209,6 +420,7 @@ macro_rules! num_conv {
         as_item! {
             approx_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_sat_to_dmax! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -230,6 +442,7 @@ macro_rules! num_conv {
         as_item! {
             approx_dmin_to_dmax! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_sat_dmin_to_dmax! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -254,6 +467,7 @@ macro_rules! num_conv {
         as_item! {
             approx_z_up! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_sat_z_up! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -275,6 +489,7 @@ macro_rules! num_conv {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_blind! { ($($attrs)*), $src, $dst, ::Wrapping }
+            approx_blind! { ($($attrs)*), $src, $dst, ::Saturating }
 
             $($attrs)*
             impl ::ValueFrom<$src> for $dst {
@@ -289,43 +504,30 @@ macro_rules! num_conv {
     };
 
     // Narrowing *into* a floating-point type where the conversion is only exact within a given range.
-    (@ $src:ty=> ($($attrs:tt)*) nf [+- $bound:expr] $dst:ident, $($tail:tt)*) => {
+    (@ $src:ty=> ($($attrs:tt)*) nf+- $dst:ident, $($tail:tt)*) => {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
-
-            $($attrs)*
-            impl ::ValueFrom<$src> for $dst {
-                type Err = ::errors::RangeError<$src>;
-                #[inline]
-                fn value_from(src: $src) -> Result<$dst, Self::Err> {
-                    if !(-$bound <= src) {
-                        return Err(::errors::RangeError::NegOverflow(src));
-                    }
-                    if !(src <= $bound) {
-                        return Err(::errors::RangeError::PosOverflow(src));
-                    }
-                    Ok(src as $dst)
-                }
-            }
+            approx_blind! { ($($attrs)*), $src, $dst, ::Saturating }
+            value_int_to_float! { ($($attrs)*), $src, $dst, abs: |s| (s as i128).wrapping_abs() as u128 }
         }
         num_conv! { @ $src=> $($tail)* }
     };
 
-    (@ $src:ty=> ($($attrs:tt)*) nf [, $max:expr] $dst:ident, $($tail:tt)*) => {
+    (@ $src:ty=> ($($attrs:tt)*) nf $dst:ident, $($tail:tt)*) => {
         as_item! {
             approx_blind! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_blind! { ($($attrs)*), $src, $dst, ::Saturating }
+            value_int_to_float! { ($($attrs)*), $src, $dst, abs: |s| s as u128 }
+        }
+        num_conv! { @ $src=> $($tail)* }
+    };
 
-            $($attrs)*
-            impl ::ValueFrom<$src> for $dst {
-                type Err = ::errors::PosOverflow<$src>;
-                #[inline]
-                fn value_from(src: $src) -> Result<$dst, Self::Err> {
-                    if !(src <= $max) {
-                        return Err(::errors::PosOverflow(src));
-                    }
-                    Ok(src as $dst)
-                }
-            }
+    // As `nf`, except that the source type's range also exceeds the destination type's finite range.
+    (@ $src:ty=> ($($attrs:tt)*) nfo $dst:ident, $($tail:tt)*) => {
+        as_item! {
+            approx_to_finite! { ($($attrs)*), $src, $dst, ::DefaultApprox }
+            approx_sat_to_finite! { ($($attrs)*), $src, $dst, ::Saturating }
+            value_int_to_float! { ($($attrs)*), $src, $dst, abs: |s| s as u128 }
         }
         num_conv! { @ $src=> $($tail)* }
     };
@@ -337,12 +539,17 @@ macro_rules! num_conv {
                 ::DefaultApprox }
             approx_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
                 ::RoundToNearest, approx: |s| s.round() }
+            approx_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
+                ::RoundToNearestEven, approx: |s| round_ties_even!(s) }
             approx_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
                 ::RoundToNegInf, approx: |s| s.floor() }
             approx_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
                 ::RoundToPosInf, approx: |s| s.ceil() }
             approx_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
                 ::RoundToZero, approx: |s| s.trunc() }
+            approx_sat_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max],
+                ::Saturating }
+            value_range_no_nan! { ($($attrs)*), $src, $dst, [$min, $max] }
         }
         num_conv! { @ $src=> $($tail)* }
     };
@@ -352,12 +559,18 @@ macro_rules! num_conv {
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::DefaultApprox }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNearest,
                 approx: |s| s.round() }
+            approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNearestEven,
+                approx: |s| round_ties_even!(s) }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToNegInf,
                 approx: |s| s.floor() }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToPosInf,
                 approx: |s| s.ceil() }
             approx_dmin_to_dmax_no_nan! { ($($attrs)*), $src, $dst, ::RoundToZero,
                 approx: |s| s.trunc() }
+            approx_sat_range_no_nan! { ($($attrs)*), $src, $dst,
+                [min_of!($dst) as $src, max_of!($dst) as $src], ::Saturating }
+            value_range_no_nan! { ($($attrs)*), $src, $dst,
+                [min_of!($dst) as $src, max_of!($dst) as $src] }
         }
         num_conv! { @ $src=> $($tail)* }
     };
@@ -368,34 +581,39 @@ macro_rules! num_conv {
 }
 
 mod lang_ints {
-    num_conv! { i8=>  w i16, w i32, w i64, w+u8, w+u16, w+u32, w+u64, w isize, w+usize }
-    num_conv! { i16=> n i8, w i32, w i64, n+u8, w+u16, w+u32, w+u64, w isize, w+usize }
-    num_conv! { i32=> n i8, n i16, w i64, n+u8, n+u16, w+u32, w+u64 }
-    num_conv! { i64=> n i8, n i16, n i32, n+u8, n+u16, n+u32, w+u64 }
+    num_conv! { i8=>  w i16, w i32, w i64, w i128, w+u8, w+u16, w+u32, w+u64, w+u128, w isize, w+usize }
+    num_conv! { i16=> n i8, w i32, w i64, w i128, n+u8, w+u16, w+u32, w+u64, w+u128, w isize, w+usize }
+    num_conv! { i32=> n i8, n i16, w i64, w i128, n+u8, n+u16, w+u32, w+u64, w+u128 }
+    num_conv! { i64=> n i8, n i16, n i32, w i128, n+u8, n+u16, n+u32, w+u64, w+u128 }
+    num_conv! { i128=> n i8, n i16, n i32, n i64, n+u8, n+u16, n+u32, n+u64, w+u128 }
     num_conv! { i32=> #[32] e isize, #[64] w isize, w+usize }
     num_conv! { i64=> #[32] n isize, #[64] e isize, #[32] n+usize, #[64] w+usize }
 
-    num_conv! { u8=> n-i8, w i16, w i32, w i64, w u16, w u32, w u64, w isize, w usize }
-    num_conv! { u16=> n-i8, n-i16, w i32, w i64, n-u8, w u32, w u64, w isize, w usize }
-    num_conv! { u32=> n-i8, n-i16, n-i32, w i64, n-u8, n-u16, w u64 }
-    num_conv! { u64=> n-i8, n-i16, n-i32, n-i64, n-u8, n-u16, n-u32 }
+    num_conv! { u8=> n-i8, w i16, w i32, w i64, w i128, w u16, w u32, w u64, w u128, w isize, w usize }
+    num_conv! { u16=> n-i8, n-i16, w i32, w i64, w i128, n-u8, w u32, w u64, w u128, w isize, w usize }
+    num_conv! { u32=> n-i8, n-i16, n-i32, w i64, w i128, n-u8, n-u16, w u64, w u128 }
+    num_conv! { u64=> n-i8, n-i16, n-i32, n-i64, w i128, n-u8, n-u16, n-u32, w u128 }
+    num_conv! { u128=> n-i8, n-i16, n-i32, n-i64, n-i128, n-u8, n-u16, n-u32, n-u64 }
     num_conv! { u32=> #[32] n-isize, #[64] w isize, #[32] e usize, #[64] w usize }
     num_conv! { u64=> n-isize, #[32] n-usize, #[64] e usize }
+    num_conv! { i128=> n isize, n+usize }
+    num_conv! { u128=> n-isize, n-usize }
 
     num_conv! { isize=> n i8, n i16, #[32] e i32, #[32] w i64, #[64] n i32, #[64] e i64 }
     num_conv! { isize=> n+u8, n+u16, #[32] w+u32, #[32] w+u64, #[64] n+u32, #[64] w+u64 }
-    num_conv! { isize=> w+usize }
+    num_conv! { isize=> w+usize, w i128, w+u128 }
 
     num_conv! { usize=> n-i8, n-i16, #[32] n-i32, #[32] w i64, #[64] n-i32, #[64] n-i64 }
     num_conv! { usize=> n-u8, n-u16, #[32] e u32, #[32] w u64, #[64] n-u32, #[64] e u64 }
-    num_conv! { usize=> n-isize }
+    num_conv! { usize=> n-isize, w i128, w u128 }
 }
 
 #[cfg(feature = "std")]
 mod lang_floats {
-    use {ApproxFrom, ApproxScheme};
+    use {ApproxFrom, ApproxScheme, Saturating};
+    use {RoundToNearest, RoundToNegInf, RoundToPosInf, RoundToZero};
     use ValueFrom;
-    use errors::{NoError, RangeError};
+    use errors::{Inexact, NoError, RangeError};
 
     // f32 -> f64: strictly widening
     impl<Scheme> ApproxFrom<f32, Scheme> for f64
@@ -432,24 +650,139 @@ mod lang_floats {
             Ok(src as f32)
         }
     }
+
+    impl ValueFrom<f64> for f32 {
+        type Err = Inexact<f64>;
+        #[inline]
+        fn value_from(src: f64) -> Result<f32, Self::Err> {
+            let dst = src as f32;
+            if !(dst as f64 == src || src.is_nan()) {
+                return Err(Inexact(src));
+            }
+            Ok(dst)
+        }
+    }
+
+    /*
+    The directed rounding schemes can't just use `as`, since that always rounds to nearest, with ties to even.  Instead, we take the nearest value and then step it by one unit in the last place if it lies on the wrong side of the input.
+
+    The ranges are chosen such that the *rounded* result lies within `f32`'s finite range: rounding toward zero (for example) can take values just beyond `f32::MAX` back down to it.
+    */
+    fn f32_next_up(v: f32) -> f32 {
+        if v == 0.0 {
+            return f32::from_bits(1);
+        }
+        let bits = v.to_bits();
+        if v > 0.0 {
+            f32::from_bits(bits + 1)
+        } else {
+            f32::from_bits(bits - 1)
+        }
+    }
+
+    fn f32_next_down(v: f32) -> f32 {
+        -f32_next_up(-v)
+    }
+
+    macro_rules! approx_f64_to_f32 {
+        ($scheme:ty, [$min:expr, $max:expr], |$src:ident, $near:ident| $adjust:expr) => {
+            impl ApproxFrom<f64, $scheme> for f32 {
+                type Err = RangeError<f64>;
+                #[inline]
+                fn approx_from(src: f64) -> Result<f32, Self::Err> {
+                    if !src.is_finite() {
+                        return Ok(src as f32);
+                    }
+                    if !($min <= src) {
+                        return Err(RangeError::NegOverflow(src));
+                    }
+                    if !(src <= $max) {
+                        return Err(RangeError::PosOverflow(src));
+                    }
+                    let near = match src as f32 {
+                        v if v.is_finite() => v,
+                        _ if src < 0.0 => ::std::f32::MIN,
+                        _ => ::std::f32::MAX,
+                    };
+                    let ($src, $near) = (src, near);
+                    Ok($adjust)
+                }
+            }
+        };
+    }
+
+    approx_f64_to_f32! { RoundToNearest,
+        [-3.4028235677973362e38, 3.4028235677973362e38],
+        |src, near| {
+            let away = if src < 0.0 { f32_next_down(near) } else { f32_next_up(near) };
+            if (near as f64).abs() < src.abs() && src - near as f64 == away as f64 - src {
+                away
+            } else {
+                near
+            }
+        }
+    }
+
+    approx_f64_to_f32! { RoundToNegInf,
+        [::std::f32::MIN as f64, 3.4028236692093843e38],
+        |src, near| if src < near as f64 { f32_next_down(near) } else { near }
+    }
+
+    approx_f64_to_f32! { RoundToPosInf,
+        [-3.4028236692093843e38, ::std::f32::MAX as f64],
+        |src, near| if (near as f64) < src { f32_next_up(near) } else { near }
+    }
+
+    approx_f64_to_f32! { RoundToZero,
+        [-3.4028236692093843e38, 3.4028236692093843e38],
+        |src, near| {
+            if (near as f64).abs() <= src.abs() {
+                near
+            } else if src < 0.0 {
+                f32_next_up(near)
+            } else {
+                f32_next_down(near)
+            }
+        }
+    }
+
+    impl ApproxFrom<f64, Saturating> for f32 {
+        type Err = NoError;
+        #[inline]
+        fn approx_from(src: f64) -> Result<f32, Self::Err> {
+            if !src.is_finite() {
+                return Ok(src as f32);
+            }
+            if !(::std::f32::MIN as f64 <= src) {
+                return Ok(::std::f32::MIN);
+            }
+            if !(src <= ::std::f32::MAX as f64) {
+                return Ok(::std::f32::MAX);
+            }
+            Ok(src as f32)
+        }
+    }
 }
 
 #[cfg(feature = "std")]
 mod lang_int_to_float {
     num_conv! { i8=>  w f32, w f64 }
     num_conv! { i16=> w f32, w f64 }
-    num_conv! { i32=> nf [+- 16_777_216] f32, w f64 }
-    num_conv! { i64=> nf [+- 16_777_216] f32, nf [+- 9_007_199_254_740_992] f64 }
+    num_conv! { i32=> nf+- f32, w f64 }
+    num_conv! { i64=> nf+- f32, nf+- f64 }
 
     num_conv! { u8=>  w f32, w f64 }
     num_conv! { u16=> w f32, w f64 }
-    num_conv! { u32=> nf [, 16_777_216] f32, w f64 }
-    num_conv! { u64=> nf [, 16_777_216] f32, nf [, 9_007_199_254_740_992] f64 }
+    num_conv! { u32=> nf f32, w f64 }
+    num_conv! { u64=> nf f32, nf f64 }
 
-    num_conv! { isize=> nf [+- 16_777_216] f32,
-        #[32] w f64, #[64] nf [+- 9_007_199_254_740_992] f64 }
-    num_conv! { usize=> nf [, 16_777_216] f32,
-        #[32] w f64, #[64] nf [, 9_007_199_254_740_992] f64 }
+    num_conv! { i128=> nf+- f32, nf+- f64 }
+    num_conv! { u128=> nfo f32, nf f64 }
+
+    num_conv! { isize=> nf+- f32,
+        #[32] w f64, #[64] nf+- f64 }
+    num_conv! { usize=> nf f32,
+        #[32] w f64, #[64] nf f64 }
 }
 
 #[cfg(feature = "std")]
@@ -470,6 +803,9 @@ mod lang_float_to_int {
         #[32] fan [0.0, 4.294967e9] usize,
         #[64] fan [-9.223372e18, 9.2233715e18] isize,
         #[64] fan [0.0, 1.8446743e19] usize }
+    num_conv! { f32=>
+        fan [-1.7014118e38, 1.7014117e38] i128,
+        fan [0.0, 3.4028235e38] u128 }
 
     num_conv! { f64=> fan i8, fan i16, fan i32,
         fan [-9.223372036854776e18, 9.223372036854775e18] i64 }
@@ -479,6 +815,9 @@ mod lang_float_to_int {
         #[32] fan isize, #[32] fan usize,
         #[64] fan [-9.223372036854776e18, 9.223372036854775e18] isize,
         #[64] fan [0.0, 1.844674407370955e19] usize }
+    num_conv! { f64=>
+        fan [-1.7014118346046923e38, 1.7014118346046921e38] i128,
+        fan [0.0, 3.4028236692093843e38] u128 }
 }
 
 mod lang_char_to_int {
@@ -540,7 +879,7 @@ mod lang_char_to_int {
     }
 
     conv_char_to_int! { i8, i16, i32, u8, u16 }
-    conv_char_to_int_wide! { i64, u64 }
+    conv_char_to_int_wide! { i64, u64, i128, u128 }
 }
 
 mod lang_int_to_char {
@@ -590,5 +929,696 @@ mod lang_int_to_char {
         };
     }
 
-    conv_int_to_char! { i8, i16, i32, i64, isize, u64, usize }
+    conv_int_to_char! { i8, i16, i32, i64, i128, isize, u64, u128, usize }
+}
+
+mod lang_num_wrappers {
+    use ApproxFrom;
+    use ApproxScheme;
+    use TryFrom;
+    use ValueFrom;
+    use std::num::Wrapping;
+    #[cfg(feature = "num_saturating")]
+    use std::num::Saturating;
+
+    /*
+    Conversions *into* a wrapper are exact under `ValueFrom`, and use the wrapper's own overflow behaviour (`$def`) under `DefaultApprox`.  Conversions *out* of a wrapper behave exactly like conversions out of the inner type.
+    */
+    macro_rules! conv_num_wrapper {
+        ($w:ident<$inner:ty>, default: $def:ty) => {
+            conv_num_wrapper! {
+                @ $w<$inner>, $def => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+            }
+        };
+
+        (@ $w:ident<$inner:ty>, $def:ty => $($ts:ty),* $(,)*) => {
+            $(
+                impl ValueFrom<$ts> for $w<$inner> {
+                    type Err = <$inner as ValueFrom<$ts>>::Err;
+                    #[inline]
+                    fn value_from(src: $ts) -> Result<$w<$inner>, Self::Err> {
+                        <$inner as ValueFrom<$ts>>::value_from(src).map($w)
+                    }
+                }
+
+                impl TryFrom<$ts> for $w<$inner> {
+                    type Err = <$inner as ValueFrom<$ts>>::Err;
+                    #[inline]
+                    fn try_from(src: $ts) -> Result<$w<$inner>, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+
+                conv_num_wrapper! { @approx $w<$inner>, $ts, ::DefaultApprox, $def }
+                conv_num_wrapper! { @approx $w<$inner>, $ts, ::Wrapping, ::Wrapping }
+                conv_num_wrapper! { @approx $w<$inner>, $ts, ::Saturating, ::Saturating }
+
+                impl ValueFrom<$w<$inner>> for $ts {
+                    type Err = <$ts as ValueFrom<$inner>>::Err;
+                    #[inline]
+                    fn value_from(src: $w<$inner>) -> Result<$ts, Self::Err> {
+                        <$ts as ValueFrom<$inner>>::value_from(src.0)
+                    }
+                }
+
+                impl TryFrom<$w<$inner>> for $ts {
+                    type Err = <$ts as ValueFrom<$inner>>::Err;
+                    #[inline]
+                    fn try_from(src: $w<$inner>) -> Result<$ts, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+
+                impl<Scheme> ApproxFrom<$w<$inner>, Scheme> for $ts
+                where Scheme: ApproxScheme, $ts: ApproxFrom<$inner, Scheme> {
+                    type Err = <$ts as ApproxFrom<$inner, Scheme>>::Err;
+                    #[inline]
+                    fn approx_from(src: $w<$inner>) -> Result<$ts, Self::Err> {
+                        <$ts as ApproxFrom<$inner, Scheme>>::approx_from(src.0)
+                    }
+                }
+            )*
+        };
+
+        (@approx $w:ident<$inner:ty>, $src:ty, $scheme:ty, $inner_scheme:ty) => {
+            impl ApproxFrom<$src, $scheme> for $w<$inner> {
+                type Err = <$inner as ApproxFrom<$src, $inner_scheme>>::Err;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$w<$inner>, Self::Err> {
+                    <$inner as ApproxFrom<$src, $inner_scheme>>::approx_from(src).map($w)
+                }
+            }
+        };
+    }
+
+    conv_num_wrapper! { Wrapping<i8>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<i16>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<i32>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<i64>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<i128>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<isize>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<u8>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<u16>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<u32>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<u64>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<u128>, default: ::Wrapping }
+    conv_num_wrapper! { Wrapping<usize>, default: ::Wrapping }
+
+    #[cfg(feature = "num_saturating")]
+    mod saturating {
+        use super::*;
+
+        conv_num_wrapper! { Saturating<i8>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<i16>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<i32>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<i64>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<i128>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<isize>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<u8>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<u16>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<u32>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<u64>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<u128>, default: ::Saturating }
+        conv_num_wrapper! { Saturating<usize>, default: ::Saturating }
+    }
+}
+
+#[cfg(feature = "nonzero")]
+mod lang_nonzero {
+    use TryFrom;
+    use ValueFrom;
+    use errors::{GeneralError, Unrepresentable};
+    use std::num::{
+        NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize,
+        NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    };
+
+    /*
+    Sources marked `w` always fit in the inner type, so zero is the only possible failure.  Those marked `n` may also overflow.
+    */
+    macro_rules! conv_int_nonzero {
+        ($nz:ident($inner:ty) <=> $($(#[$attr:meta])* $kind:ident $ts:ty),* $(,)*) => {
+            $(
+                conv_int_nonzero! { @$kind ($(#[$attr])*) $nz($inner), $ts }
+            )*
+        };
+
+        (@w ($($attrs:tt)*) $nz:ident($inner:ty), $ts:ty) => {
+            as_item! {
+                $($attrs)*
+                impl ValueFrom<$ts> for $nz {
+                    type Err = Unrepresentable<$ts>;
+                    #[inline]
+                    fn value_from(src: $ts) -> Result<$nz, Self::Err> {
+                        $nz::new(src as $inner).ok_or_else(|| Unrepresentable(src))
+                    }
+                }
+            }
+            conv_int_nonzero! { @common ($($attrs)*) $nz($inner), $ts }
+        };
+
+        (@n ($($attrs:tt)*) $nz:ident($inner:ty), $ts:ty) => {
+            as_item! {
+                $($attrs)*
+                impl ValueFrom<$ts> for $nz {
+                    type Err = GeneralError<$ts>;
+                    #[inline]
+                    fn value_from(src: $ts) -> Result<$nz, Self::Err> {
+                        let v = <$inner as ValueFrom<$ts>>::value_from(src)?;
+                        $nz::new(v).ok_or_else(|| GeneralError::Unrepresentable(src))
+                    }
+                }
+            }
+            conv_int_nonzero! { @common ($($attrs)*) $nz($inner), $ts }
+        };
+
+        (@common ($($attrs:tt)*) $nz:ident($inner:ty), $ts:ty) => {
+            as_item! {
+                $($attrs)*
+                impl TryFrom<$ts> for $nz {
+                    type Err = <$nz as ValueFrom<$ts>>::Err;
+                    #[inline]
+                    fn try_from(src: $ts) -> Result<$nz, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+
+                $($attrs)*
+                impl ValueFrom<$nz> for $ts {
+                    type Err = <$ts as ValueFrom<$inner>>::Err;
+                    #[inline]
+                    fn value_from(src: $nz) -> Result<$ts, Self::Err> {
+                        <$ts as ValueFrom<$inner>>::value_from(src.get())
+                    }
+                }
+
+                $($attrs)*
+                impl TryFrom<$nz> for $ts {
+                    type Err = <$ts as ValueFrom<$inner>>::Err;
+                    #[inline]
+                    fn try_from(src: $nz) -> Result<$ts, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            }
+        };
+    }
+
+    macro_rules! conv_nonzero {
+        ($src:ident($src_inner:ty) => $($dst:ident($dst_inner:ty)),* $(,)*) => {
+            $(
+                impl ValueFrom<$src> for $dst {
+                    type Err = <$dst_inner as ValueFrom<$src_inner>>::Err;
+                    #[inline]
+                    fn value_from(src: $src) -> Result<$dst, Self::Err> {
+                        let v = <$dst_inner as ValueFrom<$src_inner>>::value_from(src.get())?;
+                        // An exact conversion cannot turn a non-zero value into zero.
+                        Ok($dst::new(v).expect("non-zero value converted to zero"))
+                    }
+                }
+
+                impl TryFrom<$src> for $dst {
+                    type Err = <$dst_inner as ValueFrom<$src_inner>>::Err;
+                    #[inline]
+                    fn try_from(src: $src) -> Result<$dst, Self::Err> {
+                        ValueFrom::value_from(src)
+                    }
+                }
+            )*
+        };
+    }
+
+    conv_int_nonzero! { NonZeroI8(i8) <=> w i8, n i16, n i32, n i64, n i128, n isize,
+        n u8, n u16, n u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroI16(i16) <=> w i8, w i16, n i32, n i64, n i128, n isize,
+        w u8, n u16, n u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroI32(i32) <=> w i8, w i16, w i32, n i64, n i128, n isize,
+        w u8, w u16, n u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroI64(i64) <=> w i8, w i16, w i32, w i64, n i128, w isize,
+        w u8, w u16, w u32, n u64, n u128, #[cfg(target_pointer_width="32")] w usize, #[cfg(target_pointer_width="64")] n usize }
+    conv_int_nonzero! { NonZeroI128(i128) <=> w i8, w i16, w i32, w i64, w i128, w isize,
+        w u8, w u16, w u32, w u64, n u128, w usize }
+    conv_int_nonzero! { NonZeroIsize(isize) <=> w i8, w i16, w i32, #[cfg(target_pointer_width="32")] n i64, #[cfg(target_pointer_width="64")] w i64, n i128, w isize,
+        w u8, w u16, #[cfg(target_pointer_width="32")] n u32, #[cfg(target_pointer_width="64")] w u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroU8(u8) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, n u16, n u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroU16(u16) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, w u16, n u32, n u64, n u128, n usize }
+    conv_int_nonzero! { NonZeroU32(u32) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, w u16, w u32, n u64, n u128, #[cfg(target_pointer_width="32")] w usize, #[cfg(target_pointer_width="64")] n usize }
+    conv_int_nonzero! { NonZeroU64(u64) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, w u16, w u32, w u64, n u128, w usize }
+    conv_int_nonzero! { NonZeroU128(u128) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, w u16, w u32, w u64, w u128, w usize }
+    conv_int_nonzero! { NonZeroUsize(usize) <=> n i8, n i16, n i32, n i64, n i128, n isize,
+        w u8, w u16, w u32, #[cfg(target_pointer_width="32")] n u64, #[cfg(target_pointer_width="64")] w u64, n u128, w usize }
+
+    conv_nonzero! { NonZeroI8(i8)=> NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroI16(i16)=> NonZeroI8(i8), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroI32(i32)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroI64(i64)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroI128(i128)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroIsize(isize)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+
+    conv_nonzero! { NonZeroU8(u8)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroU16(u16)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroU32(u32)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroU64(u64)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU128(u128), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroU128(u128)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroUsize(usize) }
+    conv_nonzero! { NonZeroUsize(usize)=> NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128) }
+}
+
+#[cfg(all(feature = "half", feature = "std"))]
+mod lang_half {
+    use half::{bf16, f16};
+    use {ApproxFrom, ApproxScheme, DefaultApprox, Saturating, ValueFrom};
+    use {RoundToNearest, RoundToNearestEven, RoundToNegInf, RoundToPosInf, RoundToZero};
+    use errors::{
+        FloatError, FloatValueError, Inexact, NoError, RangeError, Unrepresentable,
+    };
+
+    /*
+    `half`'s own conversions from `f64` are not correctly rounded in all cases (and only round to nearest in any event), so they're only used to get within a unit in the last place of the input.  From there, we walk to the representable values either side of the input and pick between them according to the rounding mode.
+
+    Integers are first converted to `f64` by rounding to odd, which preserves enough information for the second rounding to be correct.
+    */
+    trait Half: Copy {
+        fn from_f64(v: f64) -> Self;
+        fn to_f64(self) -> f64;
+        fn from_bits(bits: u16) -> Self;
+        fn to_bits(self) -> u16;
+        fn max() -> Self;
+    }
+
+    macro_rules! half_trait {
+        ($($h:ident),*) => {
+            $(
+                impl Half for $h {
+                    #[inline] fn from_f64(v: f64) -> Self { $h::from_f64(v) }
+                    #[inline] fn to_f64(self) -> f64 { $h::to_f64(self) }
+                    #[inline] fn from_bits(bits: u16) -> Self { $h::from_bits(bits) }
+                    #[inline] fn to_bits(self) -> u16 { $h::to_bits(self) }
+                    #[inline] fn max() -> Self { $h::MAX }
+                }
+            )*
+        };
+    }
+
+    half_trait! { f16, bf16 }
+
+    #[derive(Copy, Clone, PartialEq)]
+    enum Mode { Nearest, NearestEven, NegInf, PosInf, Zero }
+
+    fn step<H: Half>(v: H, up: bool) -> H {
+        let f = v.to_f64();
+        if f == 0.0 {
+            return H::from_bits(if up { 0x0001 } else { 0x8001 });
+        }
+        if (f > 0.0) == up {
+            H::from_bits(v.to_bits() + 1)
+        } else {
+            H::from_bits(v.to_bits() - 1)
+        }
+    }
+
+    /// Returns the pair of adjacent representable values which bracket the finite value `src`.
+    fn neighbours<H: Half>(src: f64) -> (H, H) {
+        let max = H::max().to_f64();
+        let near = match H::from_f64(src) {
+            v if v.to_f64() > max => H::max(),
+            v if v.to_f64() < -max => H::from_f64(-max),
+            v => v,
+        };
+        let (mut lo, mut hi) = match near.to_f64() {
+            v if v == src => return (near, near),
+            v if v < src => (near, step(near, true)),
+            _ => (step(near, false), near),
+        };
+        while hi.to_f64() < src {
+            lo = hi;
+            hi = step(hi, true);
+        }
+        while src < lo.to_f64() {
+            hi = lo;
+            lo = step(lo, false);
+        }
+        if lo.to_f64() == src {
+            return (lo, lo);
+        }
+        if hi.to_f64() == src {
+            return (hi, hi);
+        }
+        (lo, hi)
+    }
+
+    /// Returns the value of `v`, treating infinity as the next value beyond the finite range.
+    fn extended_value<H: Half>(v: H) -> f64 {
+        let f = v.to_f64();
+        if f.is_finite() {
+            return f;
+        }
+        let max = H::max();
+        let beyond = max.to_f64() + (max.to_f64() - step(max, false).to_f64());
+        if f < 0.0 { -beyond } else { beyond }
+    }
+
+    /// Rounds the finite value `src`; the result is infinite if it lies outside the finite range.
+    fn round<H: Half>(src: f64, mode: Mode) -> H {
+        let (lo, hi) = neighbours::<H>(src);
+        if lo.to_bits() == hi.to_bits() {
+            return lo;
+        }
+        match mode {
+            Mode::NegInf => lo,
+            Mode::PosInf => hi,
+            Mode::Zero => if src < 0.0 { hi } else { lo },
+            Mode::Nearest | Mode::NearestEven => {
+                let to_lo = src - extended_value(lo);
+                let to_hi = extended_value(hi) - src;
+                if to_lo < to_hi {
+                    lo
+                } else if to_hi < to_lo {
+                    hi
+                } else if mode == Mode::Nearest {
+                    if src < 0.0 { lo } else { hi }
+                } else if lo.to_bits() & 1 == 0 {
+                    lo
+                } else {
+                    hi
+                }
+            }
+        }
+    }
+
+    fn saturate<H: Half>(v: H) -> H {
+        match v.to_f64() {
+            f if f.is_finite() => v,
+            f if f < 0.0 => H::from_f64(-H::max().to_f64()),
+            _ => H::max(),
+        }
+    }
+
+    fn int_to_f64_odd(neg: bool, mag: u128) -> f64 {
+        let bits = 128 - mag.leading_zeros();
+        let v = if bits <= 53 {
+            mag as f64
+        } else {
+            let shift = bits - 53;
+            let sticky = (mag & ((1 << shift) - 1) != 0) as u128;
+            ((mag >> shift) | sticky) as f64 * 2.0f64.powi(shift as i32)
+        };
+        if neg { -v } else { v }
+    }
+
+    fn remap_float_error<T, U>(e: FloatError<T>, src: U) -> FloatError<U> {
+        match e {
+            FloatError::NegOverflow(_) => FloatError::NegOverflow(src),
+            FloatError::PosOverflow(_) => FloatError::PosOverflow(src),
+            FloatError::NotANumber(_) => FloatError::NotANumber(src),
+        }
+    }
+
+    fn remap_float_value_error<T, U>(e: FloatValueError<T>, src: U) -> FloatValueError<U> {
+        match e {
+            FloatValueError::NegOverflow(_) => FloatValueError::NegOverflow(src),
+            FloatValueError::PosOverflow(_) => FloatValueError::PosOverflow(src),
+            FloatValueError::NotANumber(_) => FloatValueError::NotANumber(src),
+            FloatValueError::Inexact(_) => FloatValueError::Inexact(src),
+        }
+    }
+
+    // half -> f32, f64: strictly widening
+    macro_rules! half_to_float {
+        ($h:ident=> $($fty:ident),*) => {
+            $(
+                impl<Scheme> ApproxFrom<$h, Scheme> for $fty
+                where Scheme: ApproxScheme {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: $h) -> Result<$fty, Self::Err> {
+                        Ok(src.to_f64() as $fty)
+                    }
+                }
+
+                impl ValueFrom<$h> for $fty {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: $h) -> Result<$fty, Self::Err> {
+                        Ok(src.to_f64() as $fty)
+                    }
+                }
+            )*
+        };
+    }
+
+    // f32, f64, half -> half: narrowing, approximate
+    macro_rules! float_to_half {
+        ($h:ident=> $($src:ident),*) => {
+            $(
+                float_to_half! { @approx $src, $h, DefaultApprox, NearestEven }
+                float_to_half! { @approx $src, $h, RoundToNearest, Nearest }
+                float_to_half! { @approx $src, $h, RoundToNearestEven, NearestEven }
+                float_to_half! { @approx $src, $h, RoundToNegInf, NegInf }
+                float_to_half! { @approx $src, $h, RoundToPosInf, PosInf }
+                float_to_half! { @approx $src, $h, RoundToZero, Zero }
+
+                impl ApproxFrom<$src, Saturating> for $h {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: $src) -> Result<$h, Self::Err> {
+                        let v = float_to_half!(@f64 $src, src);
+                        if !v.is_finite() {
+                            return Ok($h::from_f64(v));
+                        }
+                        Ok(saturate(round::<$h>(v, Mode::NearestEven)))
+                    }
+                }
+
+                impl ValueFrom<$src> for $h {
+                    type Err = Inexact<$src>;
+                    #[inline]
+                    fn value_from(src: $src) -> Result<$h, Self::Err> {
+                        let v = float_to_half!(@f64 $src, src);
+                        let dst = $h::from_f64(v);
+                        if !(dst.to_f64() == v || v.is_nan()) {
+                            return Err(Inexact(src));
+                        }
+                        Ok(dst)
+                    }
+                }
+            )*
+        };
+
+        (@f64 f32, $e:expr) => { $e as f64 };
+        (@f64 f64, $e:expr) => { $e };
+        (@f64 $h:ident, $e:expr) => { $e.to_f64() };
+
+        (@approx $src:ident, $h:ident, $scheme:ty, $mode:ident) => {
+            impl ApproxFrom<$src, $scheme> for $h {
+                type Err = RangeError<$src>;
+                #[inline]
+                fn approx_from(src: $src) -> Result<$h, Self::Err> {
+                    let v = float_to_half!(@f64 $src, src);
+                    if !v.is_finite() {
+                        return Ok($h::from_f64(v));
+                    }
+                    let dst = round::<$h>(v, Mode::$mode);
+                    if dst.is_infinite() {
+                        return Err(if v < 0.0 {
+                            RangeError::NegOverflow(src)
+                        } else {
+                            RangeError::PosOverflow(src)
+                        });
+                    }
+                    Ok(dst)
+                }
+            }
+        };
+    }
+
+    // half -> integers: as for f32, which can represent every half value
+    macro_rules! half_to_int {
+        ($h:ident=> $($ity:ident),*) => {
+            $(
+                half_to_int! { @approx $h, $ity, DefaultApprox }
+                half_to_int! { @approx $h, $ity, RoundToNearest }
+                half_to_int! { @approx $h, $ity, RoundToNearestEven }
+                half_to_int! { @approx $h, $ity, RoundToNegInf }
+                half_to_int! { @approx $h, $ity, RoundToPosInf }
+                half_to_int! { @approx $h, $ity, RoundToZero }
+
+                impl ApproxFrom<$h, Saturating> for $ity {
+                    type Err = Unrepresentable<$h>;
+                    #[inline]
+                    fn approx_from(src: $h) -> Result<$ity, Self::Err> {
+                        <$ity as ApproxFrom<f32, Saturating>>::approx_from(src.to_f32())
+                            .map_err(|_| Unrepresentable(src))
+                    }
+                }
+
+                impl ValueFrom<$h> for $ity {
+                    type Err = FloatValueError<$h>;
+                    #[inline]
+                    fn value_from(src: $h) -> Result<$ity, Self::Err> {
+                        <$ity as ValueFrom<f32>>::value_from(src.to_f32())
+                            .map_err(|e| remap_float_value_error(e, src))
+                    }
+                }
+            )*
+        };
+
+        (@approx $h:ident, $ity:ident, $scheme:ty) => {
+            impl ApproxFrom<$h, $scheme> for $ity {
+                type Err = FloatError<$h>;
+                #[inline]
+                fn approx_from(src: $h) -> Result<$ity, Self::Err> {
+                    <$ity as ApproxFrom<f32, $scheme>>::approx_from(src.to_f32())
+                        .map_err(|e| remap_float_error(e, src))
+                }
+            }
+        };
+    }
+
+    /*
+    Integers -> half.  Integers in `e` are always exact, those in `a` may be inexact, and those in `o` may also overflow the destination's finite range.
+    */
+    macro_rules! int_to_half {
+        (e $h:ident=> $($ity:ident),*) => {
+            $(
+                impl<Scheme> ApproxFrom<$ity, Scheme> for $h
+                where Scheme: ApproxScheme {
+                    type Err = NoError;
+                    #[inline]
+                    fn approx_from(src: $ity) -> Result<$h, Self::Err> {
+                        Ok($h::from_f64(src as f64))
+                    }
+                }
+
+                impl ValueFrom<$ity> for $h {
+                    type Err = NoError;
+                    #[inline]
+                    fn value_from(src: $ity) -> Result<$h, Self::Err> {
+                        Ok($h::from_f64(src as f64))
+                    }
+                }
+            )*
+        };
+
+        (a $h:ident=> $($ity:ident),*) => {
+            $(
+                int_to_half! { @approx_a $h, $ity, DefaultApprox, NearestEven }
+                int_to_half! { @approx_a $h, $ity, RoundToNearest, Nearest }
+                int_to_half! { @approx_a $h, $ity, RoundToNearestEven, NearestEven }
+                int_to_half! { @approx_a $h, $ity, RoundToNegInf, NegInf }
+                int_to_half! { @approx_a $h, $ity, RoundToPosInf, PosInf }
+                int_to_half! { @approx_a $h, $ity, RoundToZero, Zero }
+                int_to_half! { @common $h, $ity }
+            )*
+        };
+
+        (o $h:ident=> $($ity:ident),*) => {
+            $(
+                int_to_half! { @approx_o $h, $ity, DefaultApprox, NearestEven }
+                int_to_half! { @approx_o $h, $ity, RoundToNearest, Nearest }
+                int_to_half! { @approx_o $h, $ity, RoundToNearestEven, NearestEven }
+                int_to_half! { @approx_o $h, $ity, RoundToNegInf, NegInf }
+                int_to_half! { @approx_o $h, $ity, RoundToPosInf, PosInf }
+                int_to_half! { @approx_o $h, $ity, RoundToZero, Zero }
+                int_to_half! { @common $h, $ity }
+            )*
+        };
+
+        (@approx_a $h:ident, $ity:ident, $scheme:ty, $mode:ident) => {
+            impl ApproxFrom<$ity, $scheme> for $h {
+                type Err = NoError;
+                #[inline]
+                fn approx_from(src: $ity) -> Result<$h, Self::Err> {
+                    Ok(round::<$h>(int_to_half!(@f64 src), Mode::$mode))
+                }
+            }
+        };
+
+        (@approx_o $h:ident, $ity:ident, $scheme:ty, $mode:ident) => {
+            impl ApproxFrom<$ity, $scheme> for $h {
+                type Err = RangeError<$ity>;
+                #[inline]
+                fn approx_from(src: $ity) -> Result<$h, Self::Err> {
+                    let dst = round::<$h>(int_to_half!(@f64 src), Mode::$mode);
+                    if dst.is_infinite() {
+                        return Err(if dst.is_sign_negative() {
+                            RangeError::NegOverflow(src)
+                        } else {
+                            RangeError::PosOverflow(src)
+                        });
+                    }
+                    Ok(dst)
+                }
+            }
+        };
+
+        (@f64 $src:expr) => {
+            {
+                #[allow(unused_comparisons)]
+                let neg = $src < 0;
+                let mag = if neg { ($src as i128).wrapping_neg() as u128 } else { $src as u128 };
+                int_to_f64_odd(neg, mag)
+            }
+        };
+
+        (@common $h:ident, $ity:ident) => {
+            impl ApproxFrom<$ity, Saturating> for $h {
+                type Err = NoError;
+                #[inline]
+                fn approx_from(src: $ity) -> Result<$h, Self::Err> {
+                    Ok(saturate(round::<$h>(int_to_half!(@f64 src), Mode::NearestEven)))
+                }
+            }
+
+            impl ValueFrom<$ity> for $h {
+                type Err = Inexact<$ity>;
+                #[inline]
+                fn value_from(src: $ity) -> Result<$h, Self::Err> {
+                    // As for `value_int_to_float`, but the destination's range must also be checked.
+                    #[allow(unused_comparisons)]
+                    let neg = src < 0;
+                    let mag = if neg { (src as i128).wrapping_neg() as u128 } else { src as u128 };
+                    if mag > $h::MAX.to_f64() as u128
+                        || (mag != 0 && (mag >> mag.trailing_zeros()) >> $h::MANTISSA_DIGITS != 0)
+                    {
+                        return Err(Inexact(src));
+                    }
+                    Ok($h::from_f64(src as f64))
+                }
+            }
+        };
+    }
+
+    half_to_float! { f16=> f32, f64 }
+    half_to_float! { bf16=> f32, f64 }
+
+    float_to_half! { f16=> f32, f64, bf16 }
+    float_to_half! { bf16=> f32, f64, f16 }
+
+    half_to_int! { f16=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+    half_to_int! { bf16=> i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+
+    int_to_half! { e f16=> i8, u8 }
+    int_to_half! { a f16=> i16 }
+    int_to_half! { o f16=> i32, i64, i128, isize, u16, u32, u64, u128, usize }
+
+    int_to_half! { e bf16=> i8, u8 }
+    int_to_half! { a bf16=> i16, i32, i64, i128, isize, u16, u32, u64, usize }
+    int_to_half! { o bf16=> u128 }
 }
@@ -0,0 +1,211 @@
+/*!
+This module provides iterator adapters for conversions.
+
+The [`ConvIterExt`](./trait.ConvIterExt.html) extension trait, which is also re-exported from the crate root, adds methods to every iterator which convert each item using `ValueInto` or `ApproxInto`, saturate each converted item, or collect the converted items whilst reporting the position of the first failure as an [`IndexedError`](../bulk/struct.IndexedError.html).
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+# #[cfg(feature = "std")] fn std_0() {
+use conv::prelude::*;
+use conv::errors::RangeError;
+
+let v: Vec<u8> = [1i32, 2, 3].iter().cloned().value_into_each().try_collect_conv().unwrap();
+assert_eq!(v, [1, 2, 3]);
+
+let err = [1i32, 300, -1].iter().cloned().value_into_each::<u8>().try_collect_conv::<Vec<_>>().unwrap_err();
+assert_eq!((err.index(), err.into_inner()), (1, RangeError::PosOverflow(300)));
+
+let v: Vec<u8> = [1i32, 300, -1].iter().cloned().value_into_each::<u8>()
+    .saturate_each().map(UnwrapOk::unwrap_ok).collect();
+assert_eq!(v, [1, 255, 0]);
+
+let v: Vec<i8> = [0.5f32, -1.5, 2.25].iter().cloned().approx_into_each_by::<i8, RoundToNearest>()
+    .try_collect_conv().unwrap();
+assert_eq!(v, [1, -2, 2]);
+# }
+# #[cfg(feature = "std")] std_0();
+# }
+```
+*/
+
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use {ApproxInto, ApproxScheme, DefaultApprox, Saturate, ValueInto};
+use bulk::IndexedError;
+
+/**
+This extension trait adds conversion adapters to all iterators.
+*/
+pub trait ConvIterExt: Iterator + Sized {
+    /// Converts each item to `Dst` using `ValueInto`.
+    fn value_into_each<Dst>(self) -> ValueIntoEach<Self, Dst>
+    where Self::Item: ValueInto<Dst> {
+        ValueIntoEach { iter: self, _marker: PhantomData }
+    }
+
+    /// Approximates each item to `Dst` with the default scheme.
+    fn approx_into_each<Dst>(self) -> ApproxIntoEach<Self, Dst, DefaultApprox>
+    where Self::Item: ApproxInto<Dst> {
+        ApproxIntoEach { iter: self, _marker: PhantomData }
+    }
+
+    /// Approximates each item to `Dst` with a specific scheme.
+    fn approx_into_each_by<Dst, Scheme>(self) -> ApproxIntoEach<Self, Dst, Scheme>
+    where Self::Item: ApproxInto<Dst, Scheme>, Scheme: ApproxScheme {
+        ApproxIntoEach { iter: self, _marker: PhantomData }
+    }
+
+    /// Saturates each item, as `Saturate::saturate` does.
+    fn saturate_each(self) -> SaturateEach<Self>
+    where Self::Item: Saturate {
+        SaturateEach { iter: self }
+    }
+
+    /**
+    Collects the successful values of an iterator of `Result`s.
+
+    Collection stops at the first error, which is returned along with the position of the item which produced it.
+    */
+    fn try_collect_conv<C>(self) -> Result<C, IndexedError<<Self::Item as ConvResult>::Err>>
+    where Self::Item: ConvResult, C: FromIterator<<Self::Item as ConvResult>::Ok> {
+        let mut error = None;
+        let collected = C::from_iter(UntilErr {
+            iter: self.enumerate(),
+            error: &mut error,
+        });
+        match error {
+            Some(e) => Err(e),
+            None => Ok(collected),
+        }
+    }
+}
+
+impl<I> ConvIterExt for I where I: Iterator {}
+
+/**
+This trait is implemented for `Result`, and allows `ConvIterExt::try_collect_conv` to name the success and error types of an iterator's items.
+*/
+pub trait ConvResult {
+    /// The success type.
+    type Ok;
+
+    /// The error type.
+    type Err;
+
+    /// Converts `self` into a `Result`.
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> ConvResult for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+    #[inline]
+    fn into_result(self) -> Result<T, E> {
+        self
+    }
+}
+
+/**
+An iterator which converts each item using `ValueInto`.
+
+Created by `ConvIterExt::value_into_each`.
+*/
+#[derive(Clone, Debug)]
+pub struct ValueIntoEach<I, Dst> {
+    iter: I,
+    _marker: PhantomData<fn() -> Dst>,
+}
+
+impl<I, Dst> Iterator for ValueIntoEach<I, Dst> where I: Iterator, I::Item: ValueInto<Dst> {
+    type Item = Result<Dst, <I::Item as ValueInto<Dst>>::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ValueInto::value_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/**
+An iterator which converts each item using `ApproxInto` with the scheme `Scheme`.
+
+Created by `ConvIterExt::approx_into_each` and `ConvIterExt::approx_into_each_by`.
+*/
+#[derive(Clone, Debug)]
+pub struct ApproxIntoEach<I, Dst, Scheme> {
+    iter: I,
+    _marker: PhantomData<fn() -> (Dst, Scheme)>,
+}
+
+impl<I, Dst, Scheme> Iterator for ApproxIntoEach<I, Dst, Scheme>
+where I: Iterator, I::Item: ApproxInto<Dst, Scheme>, Scheme: ApproxScheme {
+    type Item = Result<Dst, <I::Item as ApproxInto<Dst, Scheme>>::Err>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(ApproxInto::approx_into)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/**
+An iterator which saturates each item.
+
+Created by `ConvIterExt::saturate_each`.
+*/
+#[derive(Clone, Debug)]
+pub struct SaturateEach<I> {
+    iter: I,
+}
+
+impl<I> Iterator for SaturateEach<I> where I: Iterator, I::Item: Saturate {
+    type Item = <I::Item as Saturate>::Output;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(Saturate::saturate)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Yields successful values until the first error, which is stashed in `error`.
+struct UntilErr<'a, I, E: 'a> {
+    iter: I,
+    error: &'a mut Option<IndexedError<E>>,
+}
+
+impl<'a, I, R> Iterator for UntilErr<'a, I, R::Err>
+where I: Iterator<Item = (usize, R)>, R: ConvResult {
+    type Item = R::Ok;
+
+    fn next(&mut self) -> Option<R::Ok> {
+        if self.error.is_some() {
+            return None;
+        }
+        match self.iter.next() {
+            Some((i, r)) => match r.into_result() {
+                Ok(v) => Some(v),
+                Err(e) => {
+                    *self.error = Some(IndexedError::new(i, e));
+                    None
+                },
+            },
+            None => None,
+        }
+    }
+}
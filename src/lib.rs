@@ -30,10 +30,55 @@ In addition, `From`/`Into` requires all conversions to succeed or panic.  All co
 
 ## Compatibility
 
-`conv` is compatible with Rust 1.2 and higher.
+`conv` is compatible with Rust 1.26 and higher.
+
+The optional `core_convert` feature, which bridges to the `TryFrom` and `TryInto` traits in `core::convert`, requires Rust 1.34 or higher.
+
+The optional `context` feature, which records type names in conversion errors, requires Rust 1.38 or higher.
+
+The optional `nonzero` feature, which adds conversions for the `core::num::NonZero*` integer types, requires Rust 1.34 or higher.
+
+The optional `num_saturating` feature, which adds support for `core::num::Saturating`, requires Rust 1.74 or higher.
+
+The optional `const_generics` feature, which adds array conversions to the `bulk` module, requires Rust 1.55 or higher.
+
+The optional `simd` feature, which adds vectorised slice conversions to the `bulk` module, requires Rust 1.27 or higher, and the `std` feature.
+
+The optional `half` feature, which adds conversions for the `f16` and `bf16` types from the [`half`](https://crates.io/crates/half) crate, requires Rust 1.81 or higher, as required by current releases of `half` 2.  Conversions involving these types also require the `std` feature.
 
 ## Change Log
 
+### Unreleased
+
+- Added `i128`/`u128` conversions.  This raises the minimum supported Rust version to 1.26.
+- Added the `Saturating` approximation scheme.
+- Added the `RoundToNearestEven` approximation scheme for float → int conversions.
+- Added `ValueFrom<f64> for f32`, which fails with the new `Inexact` error if the input does not round-trip exactly.
+- Added `ApproxFrom<f64>` for `f32` with the `RoundToNearest`, `RoundToNegInf`, `RoundToPosInf` and `RoundToZero` schemes.
+- Added `ValueFrom` for float → int conversions, which fail with the new `FloatValueError` unless the input is an exactly representable integer.
+- Added the companion `conv-derive` crate, providing `#[derive(TryFrom)]`, `#[derive(ValueFrom)]` and `#[derive(ApproxFrom)]`.
+- Added the `ValueFrom!` macro, which derives lossless conversions from an enum into its discriminant type and every wider integer type.
+- `TryFrom!` now accepts a catch-all tuple variant marked with `#[conv(other)]`, making the conversion infallible.
+- `TryFrom!` can now derive `TryFrom<&str>` from variant names, with optional aliases and case-insensitivity, along with the reverse name lookup and `Display`.
+- Added the `Ranged!` macro, which derives range-checked conversions from every integer type into an integer newtype.
+- Added the `core_convert` feature, providing the `compat` module of adapters to and from `core::convert::TryFrom`, and `ConvUtil::try_as_std`.
+- Added the `context` feature, providing the `context` module for wrapping conversion errors with the source and destination type names.  The types involved must implement `ContextType`, which supplies the limits shown for overflows.
+- Added the `Verbose` error wrapper, whose `Debug` output includes the input value.
+- **Breaking change**: Added a `NotANumber` variant to `GeneralError` and `GeneralErrorKind`.  NaN inputs are no longer reported as `Unrepresentable` once unified into these types.
+- Added `FloatErrorKind`, the payload-less version of `FloatError`.
+- **Breaking change**: Integer → float `ValueFrom` conversions now fail with `Inexact` instead of `RangeError`/`PosOverflow`, and succeed for any exactly representable value, rather than only those within the continuous range of exactly representable integers.
+- Implemented `Saturated` for `f32`, `f64`, `bool`, `char`, `Wrapping<T>` and `Duration`, and `InvalidSentinel` for `Option<T>`.
+- Added the `num_saturating` feature, which implements `Saturated` for `Saturating<T>`.
+- Implemented `Saturate` for results carrying `NegOverflow`, `PosOverflow`, `GeneralError` and `GeneralErrorKind`.
+- Added the `nonzero` feature, providing `ValueFrom` and `TryFrom` conversions between the primitive integers and the `NonZero*` integer types, and amongst the `NonZero*` types themselves.  Conversions into a `NonZero*` type report zero as `Unrepresentable`, using `GeneralError` only where the source may also overflow.  `Saturated` is also implemented for the `NonZero*` types, with the unsigned types saturating to one rather than zero.
+- Added conversions between the primitive integers and `Wrapping<T>` (and `Saturating<T>` with the `num_saturating` feature).  `ValueFrom` into a wrapper is exact, whilst `DefaultApprox` uses the wrapper's own overflow behaviour; conversions out of a wrapper behave like those of the inner type.
+- Added the `time` module, providing checked conversions between `Duration` and the numeric types, with the `Secs`, `Millis`, `Micros` and `Nanos` unit schemes.
+- Added the `half` feature, providing conversions between `half::f16`/`half::bf16` and the primitive integer and float types (and each other), with all rounding schemes.
+- Added the `bulk` module, providing slice and `Vec` conversions which report the index of the failing element, along with saturating and sentinel-filling variants.  The `const_generics` feature adds array conversions.
+- Added the `simd` feature, providing the `bulk::simd` module of SSE2/AVX2 slice conversions for `f32` → `i16` and `i16`/`u8` → `f32`, with results identical to the scalar conversions.
+- Added the `iter` module and the `ConvIterExt` extension trait, providing the `value_into_each`, `approx_into_each`, `approx_into_each_by` and `saturate_each` iterator adapters, and `try_collect_conv`, which reports the position of the first failed conversion.
+- Added the `norm` module, providing the `Normalized` and `SignedNormalized` schemes for UNORM and SNORM conversions between integers and `f32`/`f64`, with saturating variants.
+
 ### v0.3.4
 
 - Made `TryFrom!` macro more robust.  Thanks to JeffBelgum.
@@ -89,15 +134,26 @@ These extension methods are provided to help with some common cases:
 - [`ConvUtil::value_as<Dst>`](./trait.ConvUtil.html#method.value_as) - converts to `Dst` using `ValueInto::value_into`.
 - [`ConvAsUtil::approx`](./trait.ConvAsUtil.html#method.approx) - approximates to an inferred destination type with the `DefaultApprox` scheme.
 - [`ConvAsUtil::approx_by<S>`](./trait.ConvAsUtil.html#method.approx_by) - approximates to an inferred destination type with the scheme `S`.
+- [`ConvIterExt`](./iter/trait.ConvIterExt.html) - converts or saturates each item of an iterator, and collects the results, reporting the position of the first failure.
 - [`Saturate::saturate`](./errors/trait.Saturate.html#tymethod.saturate) - saturates on overflow.
 - [`UnwrapOk::unwrap_ok`](./errors/trait.UnwrapOk.html#tymethod.unwrap_ok) - unwraps results from conversions that cannot fail.
 - [`UnwrapOrInf::unwrap_or_inf`](./errors/trait.UnwrapOrInf.html#tymethod.unwrap_or_inf) - saturates to ±∞ on failure.
 - [`UnwrapOrInvalid::unwrap_or_invalid`](./errors/trait.UnwrapOrInvalid.html#tymethod.unwrap_or_invalid) - substitutes the target type's "invalid" sentinel value on failure.
 - [`UnwrapOrSaturate::unwrap_or_saturate`](./errors/trait.UnwrapOrSaturate.html#tymethod.unwrap_or_saturate) - saturates to the maximum or minimum value of the target type on failure.
 
-A macro is provided to assist in implementing conversions:
+Macros are provided to assist in implementing conversions:
 
 - [`TryFrom!`](./macros/index.html#tryfrom!) - derives an implementation of [`TryFrom`](./trait.TryFrom.html).
+- [`ValueFrom!`](./macros/index.html#valuefrom!) - derives implementations of [`ValueFrom`](./trait.ValueFrom.html) from an enum into integer types.
+- [`Ranged!`](./macros/index.html#ranged!) - derives range-checked conversions for an integer newtype.
+
+The companion [`conv-derive`](https://crates.io/crates/conv-derive) crate provides `#[derive(TryFrom)]`, `#[derive(ValueFrom)]` and `#[derive(ApproxFrom)]` for enums and single-field newtypes.
+
+Conversions between `Duration` and the numeric types, with selectable units, are provided by the [`time`](./time/index.html) module.
+
+Conversions between integers and floats which treat the integers as fractions of `[0, 1]` or `[-1, 1]`, following the UNORM and SNORM rules used by graphics APIs, are provided by the [`norm`](./norm/index.html) module.
+
+Whole slices and arrays can be converted, reporting the index of any element which fails, using the functions in the [`bulk`](./bulk/index.html) module.
 
 If you are implementing your own types, you may also be interested in the traits contained in the [`misc`](./misc/index.html) module.
 
@@ -108,7 +164,7 @@ The crate provides several blanket implementations:
 - `*From<A> for A` (all types can be converted from and into themselves).
 - `*Into<Dst> for Src where Dst: *From<Src>` (`*From` implementations imply a matching `*Into` implementation).
 
-Conversions for the builtin numeric (integer and floating point) types are provided.  In general, `ValueFrom` conversions exist for all pairs except for float → integer (since such a conversion is generally unlikely to *exactly* succeed) and `f64 → f32` (for the same reason).  `ApproxFrom` conversions with the `DefaultApprox` scheme exist between all pairs.  `ApproxFrom` with the `Wrapping` scheme exist between integers.
+Conversions for the builtin numeric (integer and floating point) types are provided.  `ValueFrom` conversions exist between all pairs.  `ApproxFrom` conversions with the `DefaultApprox` scheme exist between all pairs.  `ApproxFrom` conversions with the `RoundTo*` schemes exist from floats to integers, and from `f64` to `f32`.  `ApproxFrom` with the `Wrapping` scheme exist between integers.  `ApproxFrom` with the `Saturating` scheme exist between all pairs.
 
 ## Errors
 
@@ -117,16 +173,22 @@ A number of error types are defined in the [`errors`](./errors/index.html) modul
 - `ValueFrom<u8> for u16` cannot possibly fail, and as such it uses `NoError`.
 - `ValueFrom<i8> for u16` can *only* fail with a negative overflow, thus it uses the `NegOverflow` type.
 - `ValueFrom<i32> for u16` can overflow in either direction, hence it uses `RangeError`.
-- Finally, `ApproxFrom<f32> for u16` can overflow (positive or negative), or attempt to convert NaN; `FloatError` covers those three cases.
+- `ApproxFrom<f32> for u16` can overflow (positive or negative), or attempt to convert NaN; `FloatError` covers those three cases.
+- `ValueFrom<f64> for f32` and `ValueFrom<i32> for f32` can only fail because the input cannot be represented *exactly*; hence `Inexact`.
+- Finally, `ValueFrom<f32> for u16` can fail in any of the ways `ApproxFrom` can, *and* if the input has a fractional part; `FloatValueError` covers all four cases.
 
 Because there are *numerous* error types, the `GeneralError` enum is provided.  `From<E, T> for GeneralError<T>` exists for each error type `E<T>` defined by this crate (even for `NoError`!), allowing errors to be translated automatically by `try!`.  In fact, all errors can be "expanded" to *all* more general forms (*e.g.* `NoError` → `NegOverflow`, `PosOverflow` → `RangeError` → `FloatError`).
 
 Aside from `NoError`, the various error types wrap the input value that you attempted to convert.  This is so that non-`Copy` types do not need to be pre-emptively cloned prior to conversion, just in case the conversion fails.  A downside is that this means there are many, *many* incompatible error types.
 
-To help alleviate this, there is also `GeneralErrorKind`, which is simply `GeneralError<T>` without the payload, and all errors can be converted into it directly.
+To help alleviate this, there is also `GeneralErrorKind`, which is simply `GeneralError<T>` without the payload, and all errors can be converted into it directly.  Likewise, `RangeErrorKind` and `FloatErrorKind` are payload-less versions of `RangeError` and `FloatError` respectively.
 
 The reason for not just using `GeneralErrorKind` in the first place is to statically reduce the number of potential error cases you need to deal with.  It also allows the `Unwrap*` extension traits to be defined *without* the possibility for runtime failure (*e.g.* you cannot use `unwrap_or_saturate` with a `FloatError`, because what do you do if the error is `NotANumber`; saturate to max or to min?  Or panic?).
 
+Because the error types must implement `Debug` for *any* input type, their `Debug` output does not include the input value.  Wrapping an error in `Verbose` (*e.g.* using `map_err(Verbose)`) produces `Debug` output which does, provided the input type implements `Debug`.
+
+With the `context` feature enabled, the [`context`](./context/index.html) module can wrap any of these errors in a `ConvError`, which also records the source and destination types for diagnostic purposes.
+
 # Examples
 
 ```
@@ -169,15 +231,17 @@ assert_eq!(400u16.approx_as_by::<u8, Wrapping>(), Ok(144));
 
 # #[cfg(feature = "std")] fn std_0() {
 // Integer -> float conversions *can* fail due to limited precision.
-// Once the continuous range of exactly representable integers is exceeded, the
-// provided implementations fail with overflow errors.
+// Conversions succeed only if the specific value is exactly representable,
+// even outside the continuous range of exactly representable integers.
 assert_eq!(f32::value_from(16_777_216i32), Ok(16_777_216.0f32));
-assert_eq!(f32::value_from(16_777_217i32), Err(RangeError::PosOverflow(16_777_217)));
+assert_eq!(f32::value_from(16_777_217i32), Err(Inexact(16_777_217)));
+assert_eq!(f32::value_from(16_777_218i32), Ok(16_777_218.0f32));
+
+// Float -> integer value conversions only succeed if the input is already an
+// integer.  In general, such conversions have to be done using approximations.
+assert_eq!(42.0f32.value_as::<u8>(), Ok(42u8));
+assert_eq!(41.5f32.value_as::<u8>(), Err(FloatValueError::Inexact(41.5)));
 
-// Float -> integer conversions have to be done using approximations.  Although
-// exact conversions are *possible*, "advertising" this with an implementation
-// is misleading.
-//
 // Note that `DefaultApprox` for float -> integer uses whatever rounding
 // mode is currently active (*i.e.* whatever `as` would do).
 assert_eq!(41.0f32.approx(), Ok(41u8));
@@ -227,18 +291,22 @@ fn too_many_errors() -> Result<(), GeneralErrorKind> {
 #[cfg(not(feature = "std"))] extern crate core as std;
 
 #[macro_use] extern crate custom_derive;
+#[cfg(feature = "half")] extern crate half;
 
 // Exported macros.
 pub mod macros;
 
 pub use errors::{
-    NoError, GeneralError, GeneralErrorKind, Unrepresentable,
+    NoError, GeneralError, GeneralErrorKind, Unrepresentable, Inexact,
     NegOverflow, PosOverflow,
-    FloatError, RangeError, RangeErrorKind,
+    FloatError, FloatErrorKind, FloatValueError, RangeError, RangeErrorKind,
     Saturate,
     UnwrapOk, UnwrapOrInf, UnwrapOrInvalid, UnwrapOrSaturate,
+    Verbose,
 };
 
+pub use iter::ConvIterExt;
+
 #[cfg(not(feature = "std"))]
 /**
 A conversion error. Corresponds to std::error:Error.
@@ -263,8 +331,8 @@ pub mod prelude {
         GeneralError, GeneralErrorKind,
         Saturate,
         UnwrapOk, UnwrapOrInf, UnwrapOrInvalid, UnwrapOrSaturate,
-        ConvUtil, ConvAsUtil,
-        RoundToNearest, RoundToZero, Wrapping,
+        ConvUtil, ConvAsUtil, ConvIterExt,
+        RoundToNearest, RoundToZero, Saturating, Wrapping,
     };
 }
 
@@ -286,8 +354,16 @@ macro_rules! item_for_each {
     };
 }
 
+pub mod bulk;
+#[cfg(feature = "core_convert")]
+pub mod compat;
+#[cfg(feature = "context")]
+pub mod context;
 pub mod errors;
+pub mod iter;
 pub mod misc;
+pub mod norm;
+pub mod time;
 
 mod impls;
 
@@ -368,12 +444,28 @@ In abstract, this can be viewed as the opposite of rounding: rather than preserv
 pub enum Wrapping {}
 impl ApproxScheme for Wrapping {}
 
+/**
+This scheme is used to convert a value by "saturating" it into a narrower range.
+
+Values which lie outside the destination type's representable range are clamped to the nearest bound of that range.  Unlike `DefaultApprox`, this means that conversions using this scheme can only fail if there *is* no nearest bound (*e.g.* when converting NaN to an integer).
+*/
+pub enum Saturating {}
+impl ApproxScheme for Saturating {}
+
 /**
 This scheme is used to convert a value by rounding it to the nearest representable value, with ties rounding away from zero.
 */
 pub enum RoundToNearest {}
 impl ApproxScheme for RoundToNearest {}
 
+/**
+This scheme is used to convert a value by rounding it to the nearest representable value, with ties rounding to the nearest even value.
+
+This is also known as "banker's rounding", and avoids the bias toward larger magnitudes that `RoundToNearest` introduces when rounding many values.
+*/
+pub enum RoundToNearestEven {}
+impl ApproxScheme for RoundToNearestEven {}
+
 /**
 This scheme is used to convert a value by rounding it toward negative infinity to the nearest representable value.
 */
@@ -517,6 +609,15 @@ pub trait ConvUtil {
         self.try_into()
     }
 
+    /// Attempt to convert the subject to a given type using `core::convert::TryInto`.
+    ///
+    /// This is only available with the `core_convert` feature enabled.
+    #[cfg(feature = "core_convert")]
+    fn try_as_std<Dst>(self) -> Result<Dst, <Self as ::std::convert::TryInto<Dst>>::Error>
+    where Self: Sized + ::std::convert::TryInto<Dst> {
+        ::std::convert::TryInto::try_into(self)
+    }
+
     /// Attempt a value conversion of the subject to a given type.
     fn value_as<Dst>(self) -> Result<Dst, Self::Err>
     where Self: Sized + ValueInto<Dst> {
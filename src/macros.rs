@@ -13,7 +13,9 @@ This macro attempts to derive an implementation of the [`TryFrom`](../trait.TryF
 
 If a conversion fails (due to there being no matching variant for the specified integer value `src`), then the conversion returns `Err(Unrepresentable(src))` (see [`Unrepresentable`](../errors/struct.Unrepresentable.html)).
 
-It is compatible with the [`custom_derive!`](https://crates.io/crates/custom_derive) macro.
+Alternatively, exactly one tuple variant with a single field of the source type may be marked with `#[conv(other)]`.  This variant absorbs any value which does not match another variant, and the conversion becomes infallible, using [`NoError`](../errors/enum.NoError.html) as its error type.  Note that `#[conv(other)]` is not a built-in attribute, so it should appear only in the input to `TryFrom!`, not on the enum definition itself (the `conv-derive` derives, by contrast, read it from the enum definition).
+
+It is compatible with the [`custom_derive!`](https://crates.io/crates/custom_derive) macro.  For a procedural `#[derive(TryFrom)]`, see the [`conv-derive`](https://crates.io/crates/conv-derive) crate.
 
 ## Example
 
@@ -62,16 +64,198 @@ TryFrom! { (i32) enum Colours {
 } }
 # fn main() {}
 ```
+
+Using a catch-all variant:
+
+```
+#[macro_use] extern crate conv;
+
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Opcode {
+    Nop,
+    Load = 0x10,
+    Store,
+    Unknown(u8),
+}
+
+TryFrom! { (u8) enum Opcode {
+    Nop,
+    Load = 0x10,
+    Store,
+    #[conv(other)]
+    Unknown(u8),
+} }
+
+fn main() {
+    use conv::{TryFrom, UnwrapOk};
+
+    assert_eq!(Opcode::try_from(0x00).unwrap_ok(), Opcode::Nop);
+    assert_eq!(Opcode::try_from(0x11).unwrap_ok(), Opcode::Store);
+    assert_eq!(Opcode::try_from(0x42).unwrap_ok(), Opcode::Unknown(0x42));
+}
+```
+
+Marking a unitary variant, or a variant with more than one field, with `#[conv(other)]` is an error:
+
+```compile_fail
+#[macro_use] extern crate conv;
+
+enum Opcode { Nop, Unknown }
+
+TryFrom! { (u8) enum Opcode { Nop, #[conv(other)] Unknown } }
+
+fn main() {}
+```
+
+```compile_fail
+#[macro_use] extern crate conv;
+
+enum Opcode { Nop, Unknown(u8, u8) }
+
+TryFrom! { (u8) enum Opcode { Nop, #[conv(other)] Unknown(u8, u8) } }
+
+fn main() {}
+```
+
+## String conversions
+
+If the source type is given as `&str`, the macro instead derives an implementation of `TryFrom<&str>` which matches the input against the names of the variants.  Additional names can be given to a variant using one or more `#[conv(alias = "...")]` attributes; as with `#[conv(other)]`, these should appear only in the input to `TryFrom!`.  Writing the source type as `&str, ignore_case` makes the comparison ignore ASCII case.
+
+If a conversion fails, the conversion returns `Err(Unrepresentable(src))`, where `src` has been copied into a `String`.  As such, this mode is only available with the `std` feature enabled.
+
+In addition, the macro derives the reverse lookup: `ValueFrom<Enum>` and `ValueFrom<&Enum>` for `&'static str`, as well as an implementation of `Display`, all of which produce the name of the variant.
+
+```
+#[macro_use] extern crate conv;
+
+#[derive(Debug, PartialEq)]
+enum Level { Debug, Info, Warning, Error }
+
+TryFrom! { (&str, ignore_case) enum Level {
+    Debug,
+    Info,
+    #[conv(alias = "warn")]
+    Warning,
+    #[conv(alias = "err")]
+    Error,
+} }
+
+fn main() {
+    use conv::{TryFrom, ValueFrom, Unrepresentable};
+
+    assert_eq!(Level::try_from("info"), Ok(Level::Info));
+    assert_eq!(Level::try_from("WARN"), Ok(Level::Warning));
+    assert_eq!(Level::try_from("fatal"), Err(Unrepresentable("fatal".to_string())));
+    assert_eq!(<&str>::value_from(&Level::Error), Ok("Error"));
+    assert_eq!(Level::Debug.to_string(), "Debug");
+}
+```
+
+# `Ranged!`
+
+```ignore
+macro_rules! Ranged {
+    (($min:expr, $max:expr) $newtype:item) => { ... };
+}
+```
+
+This macro derives range-checked conversions for a newtype wrapping a primitive integer type, where only values in the inclusive range `$min..=$max` are considered valid.  Specifically, it derives:
+
+- [`ValueFrom`](../trait.ValueFrom.html) and [`TryFrom`](../trait.TryFrom.html) from every primitive integer type.  If the input lies outside the range, the conversion returns [`RangeError`](../errors/enum.RangeError.html), indicating which side of the range was exceeded.
+- [`Saturated`](../misc/trait.Saturated.html), using the bounds of the range, so that [`unwrap_or_saturate`](../errors/trait.UnwrapOrSaturate.html) clamps to the range.
+- `ValueFrom<Newtype>` for the wrapped type, which cannot fail.
+
+It is compatible with the [`custom_derive!`](https://crates.io/crates/custom_derive) macro.
+
+## Example
+
+```
+#[macro_use] extern crate conv;
+#[macro_use] extern crate custom_derive;
+
+custom_derive! {
+    #[derive(Debug, PartialEq, Ranged(0, 100))]
+    struct Percent(u8);
+}
+
+fn main() {
+    use conv::{ValueFrom, TryFrom, UnwrapOk, UnwrapOrSaturate};
+    use conv::{NegOverflow, PosOverflow};
+
+    assert_eq!(Percent::value_from(42i32), Ok(Percent(42)));
+    assert_eq!(Percent::try_from(101u8), Err(PosOverflow(101).into()));
+    assert_eq!(Percent::value_from(-1i64), Err(NegOverflow(-1).into()));
+    assert_eq!(Percent::value_from(300u16).unwrap_or_saturate(), Percent(100));
+    assert_eq!(u8::value_from(Percent(7)).unwrap_ok(), 7);
+}
+```
+
+# `ValueFrom!`
+
+```ignore
+macro_rules! ValueFrom {
+    (($prim:ident) $enum:item) => { ... };
+}
+```
+
+This macro derives the reverse of `TryFrom!`: implementations of [`ValueFrom`](../trait.ValueFrom.html) *from* an `enum` consisting entirely of unitary variants *into* the primitive integer type `$prim`, as well as into every integer type which can losslessly represent all values of `$prim`.  Every variant's discriminant is checked at compile time, so a discriminant which does not fit in `$prim` (say, because the enum's `#[repr]` or values changed later on) causes a compile error, rather than silently truncating.
+
+These conversions cannot fail, and so use [`NoError`](../errors/enum.NoError.html) as their error type.
+
+`$prim` must be one of the integer types *other* than `isize` or `usize`; these have no width in common with the remaining types that is guaranteed on every platform.
+
+It is compatible with the [`custom_derive!`](https://crates.io/crates/custom_derive) macro.
+
+## Example
+
+```
+#[macro_use] extern crate conv;
+#[macro_use] extern crate custom_derive;
+
+custom_derive! {
+    #[derive(Debug, PartialEq, TryFrom(u8), ValueFrom(u8))]
+    enum Colours {
+        Red = 0,
+        Green = 5,
+        Blue
+    }
+}
+
+fn main() {
+    use conv::{TryFrom, ValueFrom};
+    use conv::UnwrapOk;
+
+    assert_eq!(u8::value_from(Colours::Green), Ok(5));
+    assert_eq!(i32::value_from(Colours::Blue).unwrap_ok(), 6);
+    assert_eq!(Colours::try_from(u8::value_from(Colours::Red).unwrap_ok()), Ok(Colours::Red));
+}
+```
+
+A discriminant which does not fit is rejected:
+
+```compile_fail
+#[macro_use] extern crate conv;
+
+#[repr(u16)]
+enum Wide { A = 1, B = 300 }
+
+ValueFrom! { (u8) enum Wide { A = 1, B = 300 } }
+
+fn main() {}
+```
 */
 
 /**
-This module contains symbols that we need to publicly re-export for macros, but don't want to show up as part of the public API.
+This module contains symbols that we need to publicly re-export for macros.
 
-This module has **excluded** from semver guarantees.
+The `Result`, `Ok` and `Err` re-exports are used by the code generated by [`conv-derive`](https://crates.io/crates/conv-derive), so that it works regardless of edition or `no_std`, and are covered by semver guarantees.  Everything else in this module is **excluded** from semver guarantees.
 */
-#[doc(hidden)]
 pub mod re_export {
-    pub use ::std::result::Result::{self, Ok};
+    pub use ::std::fmt;
+    pub use ::std::result::Result::{self, Ok, Err};
+    #[cfg(feature = "std")]
+    pub use ::std::string::String;
 }
 
 /**
@@ -79,16 +263,30 @@ See the documentation for the [`macros`](./macros/index.html#tryfrom!) module fo
 */
 #[macro_export]
 macro_rules! TryFrom {
+    ((&str) $(pub)* enum $name:ident { $($body:tt)* }) => {
+        TryFrom! {
+            @collect_names ($name, exact),
+            ($($body)*,) -> () []
+        }
+    };
+
+    ((&str, ignore_case) $(pub)* enum $name:ident { $($body:tt)* }) => {
+        TryFrom! {
+            @collect_names ($name, ignore_case),
+            ($($body)*,) -> () []
+        }
+    };
+
     (($prim:ty) $(pub)* enum $name:ident { $($body:tt)* }) => {
         TryFrom! {
-            @collect_variants ($name, $prim),
-            ($($body)*,) -> ()
+            @collect_variants ($name, $prim, []),
+            ($($body)*,) -> () ()
         }
     };
 
     (
-        @collect_variants ($name:ident, $prim:ty),
-        ($(,)*) -> ($($var_names:ident,)*)
+        @collect_variants ($name:ident, $prim:ty, []),
+        ($(,)*) -> ($($var_names:ident,)*) $_discrs:tt
     ) => {
         impl $crate::TryFrom<$prim> for $name {
             type Err = $crate::errors::Unrepresentable<$prim>;
@@ -98,61 +296,347 @@ macro_rules! TryFrom {
                         return $crate::macros::re_export::Ok($name::$var_names);
                     }
                 )*
-                Err($crate::errors::Unrepresentable(src))
+                $crate::macros::re_export::Err($crate::errors::Unrepresentable(src))
             }
         }
     };
 
+    (
+        @collect_variants ($name:ident, $prim:ty, [$other:ident]),
+        ($(,)*) -> ($($var_names:ident,)*) ($($discrs:tt)*)
+    ) => {
+        impl $crate::TryFrom<$prim> for $name {
+            type Err = $crate::errors::NoError;
+            fn try_from(src: $prim) -> $crate::macros::re_export::Result<$name, Self::Err> {
+                // An enum with a non-unitary variant cannot be cast with `as`, so we mirror its discriminants in one which can.
+                #[allow(dead_code)]
+                #[repr($prim)]
+                enum Discriminant { $($discrs)* }
+                $(
+                    if src == Discriminant::$var_names as $prim {
+                        return $crate::macros::re_export::Ok($name::$var_names);
+                    }
+                )*
+                $crate::macros::re_export::Ok($name::$other(src))
+            }
+        }
+    };
+
+    (
+        @collect_variants ($name:ident, $prim:ty, []),
+        (#[conv(other)] $(#[$_attrs:meta])* $var:ident ($_payload:ty $(,)*) $(= $val:expr)*, $($tail:tt)*)
+            -> $var_names:tt ($($discrs:tt)*)
+    ) => {
+        TryFrom! {
+            @collect_variants ($name, $prim, [$var]),
+            ($($tail)*) -> $var_names ($($discrs)* $var $(= $val)*,)
+        }
+    };
+
+    (
+        @collect_variants ($name:ident, $prim:ty, [$_other:ident]),
+        (#[conv(other)] $(#[$_attrs:meta])* $var:ident $($_tail:tt)*) -> $_var_names:tt $_discrs:tt
+    ) => {
+        compile_error!(concat!(
+            "cannot derive TryFrom for ",
+            stringify!($name),
+            ", due to more than one #[conv(other)] variant."
+        ));
+    };
+
+    (
+        @collect_variants ($name:ident, $($_fixed:tt)*),
+        (#[conv(other)] $(#[$_attrs:meta])* $var:ident $($_tail:tt)*) -> $_var_names:tt $_discrs:tt
+    ) => {
+        compile_error!(concat!(
+            "cannot derive TryFrom for ",
+            stringify!($name),
+            ", as the #[conv(other)] variant ",
+            stringify!($var),
+            " must be a tuple variant with a single field."
+        ));
+    };
+
     (
         @collect_variants $fixed:tt,
-        (#[$_attr:meta] $($tail:tt)*) -> $var_names:tt
+        (#[$_attr:meta] $($tail:tt)*) -> $var_names:tt $discrs:tt
     ) => {
         TryFrom! {
             @skip_meta $fixed,
-            ($($tail)*) -> $var_names
+            ($($tail)*) -> $var_names $discrs
         }
     };
 
     (
         @collect_variants $fixed:tt,
-        ($var:ident $(= $_val:expr)*, $($tail:tt)*) -> ($($var_names:tt)*)
+        ($var:ident $(= $val:expr)*, $($tail:tt)*) -> ($($var_names:tt)*) ($($discrs:tt)*)
     ) => {
         TryFrom! {
             @collect_variants $fixed,
-            ($($tail)*) -> ($($var_names)* $var,)
+            ($($tail)*) -> ($($var_names)* $var,) ($($discrs)* $var $(= $val)*,)
         }
     };
 
     (
-        @collect_variants ($name:ident),
-        ($var:ident $_struct:tt, $($tail:tt)*) -> ($($var_names:tt)*)
+        @collect_variants ($name:ident, $($_fixed:tt)*),
+        ($var:ident $_struct:tt $($_tail:tt)*) -> $_var_names:tt $_discrs:tt
     ) => {
-        const _error: () = concat!(
+        compile_error!(concat!(
             "cannot derive TryFrom for ",
             stringify!($name),
             ", due to non-unitary variant ",
             stringify!($var),
-            "."
-        );
+            " not marked with #[conv(other)]."
+        ));
+    };
+
+    (
+        @skip_meta $fixed:tt,
+        (#[conv(other)] $($tail:tt)*) -> $var_names:tt $discrs:tt
+    ) => {
+        TryFrom! {
+            @collect_variants $fixed,
+            (#[conv(other)] $($tail)*) -> $var_names $discrs
+        }
     };
-    
+
     (
         @skip_meta $fixed:tt,
-        (#[$_attr:meta] $($tail:tt)*) -> $var_names:tt
+        (#[$_attr:meta] $($tail:tt)*) -> $var_names:tt $discrs:tt
     ) => {
         TryFrom! {
             @skip_meta $fixed,
-            ($($tail)*) -> $var_names
+            ($($tail)*) -> $var_names $discrs
         }
     };
 
     (
         @skip_meta $fixed:tt,
-        ($var:ident $($tail:tt)*) -> $var_names:tt
+        ($var:ident $($tail:tt)*) -> $var_names:tt $discrs:tt
     ) => {
         TryFrom! {
             @collect_variants $fixed,
-            ($var $($tail)*) -> $var_names
+            ($var $($tail)*) -> $var_names $discrs
         }
     };
+
+    (
+        @collect_names ($name:ident, $case:ident),
+        ($(,)*) -> ($($var_names:ident [$([$($attrs:tt)*])*],)*) []
+    ) => {
+        impl<'a> $crate::TryFrom<&'a str> for $name {
+            type Err = $crate::errors::Unrepresentable<$crate::macros::re_export::String>;
+            fn try_from(src: &'a str) -> $crate::macros::re_export::Result<$name, Self::Err> {
+                $(
+                    if TryFrom!(@name_eq $case, src, stringify!($var_names))
+                        $(|| TryFrom!(@alias_eq $case, src, $($attrs)*))*
+                    {
+                        return $crate::macros::re_export::Ok($name::$var_names);
+                    }
+                )*
+                $crate::macros::re_export::Err($crate::errors::Unrepresentable(src.into()))
+            }
+        }
+
+        impl<'a> $crate::ValueFrom<&'a $name> for &'static str {
+            type Err = $crate::errors::NoError;
+            fn value_from(src: &'a $name) -> $crate::macros::re_export::Result<&'static str, Self::Err> {
+                $crate::macros::re_export::Ok(match *src {
+                    $($name::$var_names => stringify!($var_names),)*
+                })
+            }
+        }
+
+        impl $crate::ValueFrom<$name> for &'static str {
+            type Err = $crate::errors::NoError;
+            fn value_from(src: $name) -> $crate::macros::re_export::Result<&'static str, Self::Err> {
+                $crate::ValueFrom::value_from(&src)
+            }
+        }
+
+        impl $crate::macros::re_export::fmt::Display for $name {
+            fn fmt(&self, fmt: &mut $crate::macros::re_export::fmt::Formatter) -> $crate::macros::re_export::fmt::Result {
+                fmt.write_str(match *self {
+                    $($name::$var_names => stringify!($var_names),)*
+                })
+            }
+        }
+    };
+
+    (
+        @collect_names $fixed:tt,
+        (#[$($attr:tt)*] $($tail:tt)*) -> $var_names:tt [$($pending:tt)*]
+    ) => {
+        TryFrom! {
+            @collect_names $fixed,
+            ($($tail)*) -> $var_names [$($pending)* [$($attr)*]]
+        }
+    };
+
+    (
+        @collect_names $fixed:tt,
+        ($var:ident $(= $_val:expr)*, $($tail:tt)*) -> ($($var_names:tt)*) [$($pending:tt)*]
+    ) => {
+        TryFrom! {
+            @collect_names $fixed,
+            ($($tail)*) -> ($($var_names)* $var [$($pending)*],) []
+        }
+    };
+
+    (
+        @collect_names ($name:ident, $_case:ident),
+        ($var:ident $_struct:tt $($_tail:tt)*) -> $_var_names:tt $_pending:tt
+    ) => {
+        compile_error!(concat!(
+            "cannot derive TryFrom<&str> for ",
+            stringify!($name),
+            ", due to non-unitary variant ",
+            stringify!($var),
+            "."
+        ));
+    };
+
+    (@name_eq exact, $src:ident, $name:expr) => { $src == $name };
+    (@name_eq ignore_case, $src:ident, $name:expr) => { $src.eq_ignore_ascii_case($name) };
+
+    (@alias_eq $case:ident, $src:ident, conv(alias = $alias:expr)) => { TryFrom!(@name_eq $case, $src, $alias) };
+    (@alias_eq $_case:ident, $_src:ident, $($_attr:tt)*) => { false };
+}
+
+/**
+See the documentation for the [`macros`](./macros/index.html#valuefrom!) module for details.
+*/
+#[macro_export]
+macro_rules! ValueFrom {
+    (($prim:ident) $(pub)* enum $name:ident { $($body:tt)* }) => {
+        ValueFrom! {
+            @collect_variants ($name, $prim),
+            ($($body)*,) -> ()
+        }
+    };
+
+    (
+        @collect_variants ($name:ident, $prim:ident),
+        ($(,)*) -> $var_names:tt
+    ) => {
+        ValueFrom! { @wider ($name, $prim, $var_names) }
+    };
+
+    (
+        @collect_variants $fixed:tt,
+        (#[$_attr:meta] $($tail:tt)*) -> $var_names:tt
+    ) => {
+        ValueFrom! {
+            @collect_variants $fixed,
+            ($($tail)*) -> $var_names
+        }
+    };
+
+    (
+        @collect_variants $fixed:tt,
+        ($var:ident $(= $_val:expr)*, $($tail:tt)*) -> ($($var_names:tt)*)
+    ) => {
+        ValueFrom! {
+            @collect_variants $fixed,
+            ($($tail)*) -> ($($var_names)* $var)
+        }
+    };
+
+    (
+        @collect_variants ($name:ident, $_prim:ident),
+        ($var:ident $_struct:tt $($_tail:tt)*) -> $_var_names:tt
+    ) => {
+        compile_error!(concat!(
+            "cannot derive ValueFrom for ",
+            stringify!($name),
+            ", due to non-unitary variant ",
+            stringify!($var),
+            "."
+        ));
+    };
+
+    (@wider ($name:ident, u8, $vars:tt)) => { ValueFrom! { @impl $name, u8, $vars => u8, u16, u32, u64, u128, usize, i16, i32, i64, i128, isize } };
+    (@wider ($name:ident, u16, $vars:tt)) => { ValueFrom! { @impl $name, u16, $vars => u16, u32, u64, u128, usize, i32, i64, i128 } };
+    (@wider ($name:ident, u32, $vars:tt)) => { ValueFrom! { @impl $name, u32, $vars => u32, u64, u128, i64, i128 } };
+    (@wider ($name:ident, u64, $vars:tt)) => { ValueFrom! { @impl $name, u64, $vars => u64, u128, i128 } };
+    (@wider ($name:ident, u128, $vars:tt)) => { ValueFrom! { @impl $name, u128, $vars => u128 } };
+    (@wider ($name:ident, i8, $vars:tt)) => { ValueFrom! { @impl $name, i8, $vars => i8, i16, i32, i64, i128, isize } };
+    (@wider ($name:ident, i16, $vars:tt)) => { ValueFrom! { @impl $name, i16, $vars => i16, i32, i64, i128, isize } };
+    (@wider ($name:ident, i32, $vars:tt)) => { ValueFrom! { @impl $name, i32, $vars => i32, i64, i128 } };
+    (@wider ($name:ident, i64, $vars:tt)) => { ValueFrom! { @impl $name, i64, $vars => i64, i128 } };
+    (@wider ($name:ident, i128, $vars:tt)) => { ValueFrom! { @impl $name, i128, $vars => i128 } };
+
+    // Only the conversion into `$prim` itself (which comes first) checks the discriminants, to avoid repeating the errors.
+    (@impl $name:ident, $prim:ident, $vars:tt => $first:ident $(, $dst:ident)*) => {
+        ValueFrom! { @impl_one $name, $prim, $vars => $first }
+        $(
+            ValueFrom! { @impl_one $name, $prim, () => $dst }
+        )*
+    };
+
+    (@impl_one $name:ident, $prim:ident, ($($var_names:ident)*) => $dst:ident) => {
+        impl $crate::ValueFrom<$name> for $dst {
+            type Err = $crate::errors::NoError;
+            fn value_from(src: $name) -> $crate::macros::re_export::Result<$dst, Self::Err> {
+                // Each of these fails to compile if the variant's discriminant doesn't survive being cast to `$prim`.
+                $(
+                    let _: [(); 0 - !($name::$var_names as i128 == $name::$var_names as $prim as i128) as usize] = [];
+                )*
+                $crate::macros::re_export::Ok(src as $prim as $dst)
+            }
+        }
+    };
+}
+
+/**
+See the documentation for the [`macros`](./macros/index.html#ranged!) module for details.
+*/
+#[macro_export]
+macro_rules! Ranged {
+    (($min:expr, $max:expr) $(pub)* struct $name:ident ($(pub)* $inner:ty);) => {
+        Ranged! {
+            @impl_srcs ($name, $inner, $min, $max),
+            i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+        }
+
+        impl $crate::misc::Saturated for $name {
+            #[inline] fn saturated_max() -> Self { $name($max) }
+            #[inline] fn saturated_min() -> Self { $name($min) }
+        }
+
+        impl $crate::ValueFrom<$name> for $inner {
+            type Err = $crate::errors::NoError;
+            #[inline]
+            fn value_from(src: $name) -> $crate::macros::re_export::Result<$inner, Self::Err> {
+                $crate::macros::re_export::Ok(src.0)
+            }
+        }
+    };
+
+    (@impl_srcs ($name:ident, $inner:ty, $min:expr, $max:expr), $($src:ident),*) => {
+        $(
+            impl $crate::ValueFrom<$src> for $name {
+                type Err = $crate::errors::RangeError<$src>;
+                #[allow(unused_comparisons)]
+                fn value_from(src: $src) -> $crate::macros::re_export::Result<$name, Self::Err> {
+                    use $crate::macros::re_export::{Ok, Err};
+                    use $crate::errors::{NegOverflow, PosOverflow};
+                    match <$inner as $crate::ValueFrom<$src>>::value_from(src) {
+                        Ok(v) if v < $min => Err(NegOverflow(src).into()),
+                        Ok(v) if v > $max => Err(PosOverflow(src).into()),
+                        Ok(v) => Ok($name(v)),
+                        Err(e) => Err(e.into()),
+                    }
+                }
+            }
+
+            impl $crate::TryFrom<$src> for $name {
+                type Err = $crate::errors::RangeError<$src>;
+                #[inline]
+                fn try_from(src: $src) -> $crate::macros::re_export::Result<$name, Self::Err> {
+                    $crate::ValueFrom::value_from(src)
+                }
+            }
+        )*
+    };
 }
@@ -6,6 +6,8 @@ This module defines some additional traits not *directly* tied to conversions.
 This trait indicates that values of a type can be logically "saturated".
 
 This is used by the `errors::UnwrapOrSaturate` extension trait.
+
+Floating point types saturate to their largest finite values, *not* to infinity; the `SignedInfinity` trait (and `errors::UnwrapOrInf`) should be used when infinity is wanted instead.
 */
 pub trait Saturated {
     /// Returns the type's saturated, maximum value.
@@ -16,7 +18,7 @@ pub trait Saturated {
 }
 
 item_for_each! {
-    (i8), (i16), (i32), (i64), (u8), (u16), (u32), (u64), (isize), (usize) => {
+    (i8), (i16), (i32), (i64), (i128), (u8), (u16), (u32), (u64), (u128), (isize), (usize) => {
         ($ity:ident) => {
             impl Saturated for $ity {
                 #[inline] fn saturated_max() -> Self { ::std::$ity::MAX }
@@ -26,6 +28,89 @@ item_for_each! {
     }
 }
 
+item_for_each! {
+    (f32), (f64) => {
+        ($fty:ident) => {
+            impl Saturated for $fty {
+                #[inline] fn saturated_max() -> Self { ::std::$fty::MAX }
+                #[inline] fn saturated_min() -> Self { ::std::$fty::MIN }
+            }
+        };
+    }
+}
+
+#[cfg(feature = "half")]
+item_for_each! {
+    (f16), (bf16) => {
+        ($fty:ident) => {
+            impl Saturated for ::half::$fty {
+                #[inline] fn saturated_max() -> Self { ::half::$fty::MAX }
+                #[inline] fn saturated_min() -> Self { ::half::$fty::MIN }
+            }
+
+            impl InvalidSentinel for ::half::$fty {
+                #[inline] fn invalid_sentinel() -> Self { ::half::$fty::NAN }
+            }
+
+            impl SignedInfinity for ::half::$fty {
+                #[inline] fn neg_infinity() -> Self { ::half::$fty::NEG_INFINITY }
+                #[inline] fn pos_infinity() -> Self { ::half::$fty::INFINITY }
+            }
+        };
+    }
+}
+
+impl Saturated for bool {
+    #[inline] fn saturated_max() -> Self { true }
+    #[inline] fn saturated_min() -> Self { false }
+}
+
+/// `char` saturates to the lowest and highest Unicode scalar values.
+impl Saturated for char {
+    #[inline] fn saturated_max() -> Self { '\u{10FFFF}' }
+    #[inline] fn saturated_min() -> Self { '\0' }
+}
+
+impl<T> Saturated for ::std::num::Wrapping<T> where T: Saturated {
+    #[inline] fn saturated_max() -> Self { ::std::num::Wrapping(T::saturated_max()) }
+    #[inline] fn saturated_min() -> Self { ::std::num::Wrapping(T::saturated_min()) }
+}
+
+#[cfg(feature = "num_saturating")]
+impl<T> Saturated for ::std::num::Saturating<T> where T: Saturated {
+    #[inline] fn saturated_max() -> Self { ::std::num::Saturating(T::saturated_max()) }
+    #[inline] fn saturated_min() -> Self { ::std::num::Saturating(T::saturated_min()) }
+}
+
+// The unsigned `NonZero*` types saturate to one, rather than zero.
+#[cfg(feature = "nonzero")]
+item_for_each! {
+    (NonZeroU8, u8, 1), (NonZeroU16, u16, 1), (NonZeroU32, u32, 1),
+    (NonZeroU64, u64, 1), (NonZeroU128, u128, 1), (NonZeroUsize, usize, 1),
+    (NonZeroI8, i8, ::std::i8::MIN), (NonZeroI16, i16, ::std::i16::MIN), (NonZeroI32, i32, ::std::i32::MIN),
+    (NonZeroI64, i64, ::std::i64::MIN), (NonZeroI128, i128, ::std::i128::MIN), (NonZeroIsize, isize, ::std::isize::MIN) => {
+        ($nz:ident, $ity:ident, $min:expr) => {
+            impl Saturated for ::std::num::$nz {
+                #[inline]
+                fn saturated_max() -> Self {
+                    ::std::num::$nz::new(::std::$ity::MAX).expect("saturated maximum is zero")
+                }
+
+                #[inline]
+                fn saturated_min() -> Self {
+                    ::std::num::$nz::new($min).expect("saturated minimum is zero")
+                }
+            }
+        };
+    }
+}
+
+/// `Duration` saturates to zero and to the longest representable duration.
+impl Saturated for ::std::time::Duration {
+    #[inline] fn saturated_max() -> Self { ::std::time::Duration::new(::std::u64::MAX, 999_999_999) }
+    #[inline] fn saturated_min() -> Self { ::std::time::Duration::new(0, 0) }
+}
+
 /**
 This trait indicates that a type has an "invalid" sentinel value.
 
@@ -47,6 +132,10 @@ item_for_each! {
     }
 }
 
+impl<T> InvalidSentinel for Option<T> {
+    #[inline] fn invalid_sentinel() -> Self { None }
+}
+
 /**
 This trait indicates that a type has positive and negative "infinity" values.
 
@@ -0,0 +1,158 @@
+/*!
+This module provides conversions between integers and floats which treat the integers as fixed-point fractions, as graphics APIs do for colour channels and audio code does for samples.
+
+[`Normalized`](./struct.Normalized.html) follows the UNORM rules used by Direct3D and Vulkan: an unsigned integer `c` of type `T` maps to `c / T::MAX`, so that `0` maps to `0.0` and `T::MAX` maps to `1.0`.  [`SignedNormalized`](./struct.SignedNormalized.html) follows the SNORM rules: a signed integer `c` maps to `c / T::MAX`, clamped to `-1.0`.  Both `T::MIN` and `T::MIN + 1` map to `-1.0`, so that zero is exactly representable and the range is symmetric.
+
+Converting from a float multiplies by `T::MAX` and rounds to the nearest integer, with halfway cases rounded away from zero.  This matches the Direct3D rules; Vulkan leaves the treatment of halfway cases up to the implementation.
+
+Each scheme takes a parameter which determines what happens to floats outside of the range (`[0, 1]` for `Normalized`, `[-1, 1]` for `SignedNormalized`).  With `DefaultApprox`, they are reported as a `FloatError`.  With `Saturating`, they are clamped to the range first, and only NaN is reported, as `Unrepresentable`.  Note that the graphics APIs convert NaN to zero, which can be done using `unwrap_or(0)`.
+
+Conversions from integers use `f64` arithmetic, and never fail.  Conversions from floats require the `std` feature.
+
+`Normalized` is implemented for the unsigned integer types, and `SignedNormalized` for the signed integer types.
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+use conv::prelude::*;
+use conv::norm::{Normalized, SignedNormalized};
+
+assert_eq!(255u8.approx_as_by::<f32, Normalized>(), Ok(1.0));
+assert_eq!(0x8000u16.approx_as_by::<f32, Normalized>(), Ok(0.50000763));
+assert_eq!((-128i8).approx_as_by::<f32, SignedNormalized>(), Ok(-1.0));
+assert_eq!((-64i8).approx_as_by::<f64, SignedNormalized>(), Ok(-64.0 / 127.0));
+
+# #[cfg(feature = "std")] fn std_0() {
+use conv::FloatError;
+
+assert_eq!(1.0f32.approx_as_by::<u8, Normalized>(), Ok(255));
+assert_eq!(0.5f32.approx_as_by::<u8, Normalized>(), Ok(128));
+assert_eq!(1.5f32.approx_as_by::<u8, Normalized>(), Err(FloatError::PosOverflow(1.5)));
+assert_eq!(1.5f32.approx_as_by::<u8, Normalized<Saturating>>(), Ok(255));
+assert_eq!((-2.0f64).approx_as_by::<i16, SignedNormalized<Saturating>>(), Ok(-32767));
+assert_eq!(::std::f64::NAN.approx_as_by::<i16, SignedNormalized<Saturating>>().is_err(), true);
+# }
+# #[cfg(feature = "std")] std_0();
+# }
+```
+*/
+
+use std::marker::PhantomData;
+use {ApproxFrom, ApproxScheme, DefaultApprox};
+use errors::NoError;
+#[cfg(feature = "std")]
+use Saturating;
+#[cfg(feature = "std")]
+use errors::{FloatError, Unrepresentable};
+
+/**
+This scheme maps unsigned integers onto `[0, 1]`, using the Direct3D and Vulkan UNORM rules.
+
+`S` is either `DefaultApprox` or `Saturating`, and determines how floats outside of `[0, 1]` are handled.
+*/
+pub struct Normalized<S = DefaultApprox>(PhantomData<S>);
+impl<S> ApproxScheme for Normalized<S> where S: ApproxScheme {}
+
+/**
+This scheme maps signed integers onto `[-1, 1]`, using the Direct3D and Vulkan SNORM rules.
+
+`S` is either `DefaultApprox` or `Saturating`, and determines how floats outside of `[-1, 1]` are handled.
+*/
+pub struct SignedNormalized<S = DefaultApprox>(PhantomData<S>);
+impl<S> ApproxScheme for SignedNormalized<S> where S: ApproxScheme {}
+
+macro_rules! norm_int_to_float {
+    ($scheme:ident, $min:expr; $($ity:ident),* $(,)*) => {
+        $(
+            norm_int_to_float! { @impl $scheme, $min; $ity, f32 }
+            norm_int_to_float! { @impl $scheme, $min; $ity, f64 }
+        )*
+    };
+
+    (@impl $scheme:ident, $min:expr; $ity:ident, $fty:ident) => {
+        impl<S> ApproxFrom<$ity, $scheme<S>> for $fty where S: ApproxScheme {
+            type Err = NoError;
+            #[inline]
+            fn approx_from(src: $ity) -> Result<$fty, Self::Err> {
+                let v = src as f64 / ::std::$ity::MAX as f64;
+                Ok((if v < $min { $min } else { v }) as $fty)
+            }
+        }
+    };
+}
+
+norm_int_to_float! { Normalized, 0.0; u8, u16, u32, u64, u128, usize }
+norm_int_to_float! { SignedNormalized, -1.0; i8, i16, i32, i64, i128, isize }
+
+/*
+`T::MAX as f64` is rounded *up* for the 64- and 128-bit types, so scaling `1.0` produces a value just past the integer's limit.  Anything which rounds to the limits is therefore mapped directly to them, rather than cast.
+*/
+#[cfg(feature = "std")]
+macro_rules! norm_float_to_int {
+    ($scheme:ident, $min:expr, $sign:ident; $($ity:ident),* $(,)*) => {
+        $(
+            norm_float_to_int! { @impl $scheme, $min, $sign; f32, $ity }
+            norm_float_to_int! { @impl $scheme, $min, $sign; f64, $ity }
+        )*
+    };
+
+    (@impl $scheme:ident, $min:expr, $sign:ident; $fty:ident, $ity:ident) => {
+        impl ApproxFrom<$fty, $scheme<DefaultApprox>> for $ity {
+            type Err = FloatError<$fty>;
+            #[inline]
+            fn approx_from(src: $fty) -> Result<$ity, Self::Err> {
+                if src.is_nan() {
+                    return Err(FloatError::NotANumber(src));
+                }
+                if !($min <= src) {
+                    return Err(FloatError::NegOverflow(src));
+                }
+                if !(src <= 1.0) {
+                    return Err(FloatError::PosOverflow(src));
+                }
+                Ok(norm_float_to_int!(@scale $sign $ity, src as f64))
+            }
+        }
+
+        impl ApproxFrom<$fty, $scheme<Saturating>> for $ity {
+            type Err = Unrepresentable<$fty>;
+            #[inline]
+            fn approx_from(src: $fty) -> Result<$ity, Self::Err> {
+                if src.is_nan() {
+                    return Err(Unrepresentable(src));
+                }
+                let v = if src < $min { $min } else if src > 1.0 { 1.0 } else { src as f64 };
+                Ok(norm_float_to_int!(@scale $sign $ity, v))
+            }
+        }
+    };
+
+    (@scale unsigned $ity:ident, $v:expr) => {
+        {
+            let max = ::std::$ity::MAX as f64;
+            let v = ($v * max).round();
+            if v >= max { ::std::$ity::MAX } else { v as $ity }
+        }
+    };
+
+    (@scale signed $ity:ident, $v:expr) => {
+        {
+            let max = ::std::$ity::MAX as f64;
+            let v = ($v * max).round();
+            if v >= max {
+                ::std::$ity::MAX
+            } else if v <= -max {
+                -::std::$ity::MAX
+            } else {
+                v as $ity
+            }
+        }
+    };
+}
+
+#[cfg(feature = "std")]
+norm_float_to_int! { Normalized, 0.0, unsigned; u8, u16, u32, u64, u128, usize }
+#[cfg(feature = "std")]
+norm_float_to_int! { SignedNormalized, -1.0, signed; i8, i16, i32, i64, i128, isize }
@@ -0,0 +1,309 @@
+/*!
+This module provides conversions between `Duration` and the primitive numeric types.
+
+A bare number has no unit, so approximate conversions select one using a scheme: [`Secs`](./struct.Secs.html), [`Millis`](./struct.Millis.html), [`Micros`](./struct.Micros.html) or [`Nanos`](./struct.Nanos.html).  Each unit takes a rounding scheme as a parameter, which is used whenever a value falls between two whole nanoseconds (or, when converting a `Duration` into an integer, between two whole units).  The default is `DefaultApprox`, which truncates toward zero, just as `as` would.  `RoundToNearest`, `RoundToNearestEven`, `RoundToNegInf`, `RoundToPosInf` and `RoundToZero` are also supported, except when converting a `Duration` into a float, which always rounds to nearest and so only supports `DefaultApprox` and `RoundToNearest`.
+
+`ValueFrom` conversions always use seconds, and only succeed if the value is represented exactly.  For floating point values, this means a whole number of nanoseconds, and a `Duration` which converts into a float without rounding.
+
+Negative values, values too large for a `Duration`, and NaN are reported as errors, rather than panicking as the corresponding `Duration` constructors do.  Float conversions (other than `Duration` to float approximations) require the `std` feature.
+
+# Example
+
+```
+# extern crate conv;
+# fn main() {
+use std::time::Duration;
+use conv::prelude::*;
+use conv::FloatError;
+use conv::time::{Millis, Secs};
+
+assert_eq!(Duration::value_from(90u32), Ok(Duration::from_secs(90)));
+assert_eq!(1_500u64.approx_as_by::<Duration, Millis>(), Ok(Duration::from_millis(1_500)));
+assert_eq!(Duration::from_millis(1_999).approx_as_by::<u64, Secs>(), Ok(1));
+assert_eq!(Duration::from_millis(1_999).approx_as_by::<u64, Secs<RoundToNearest>>(), Ok(2));
+assert_eq!(Duration::from_millis(1_500).approx_as_by::<f64, Secs>(), Ok(1.5));
+
+# #[cfg(feature = "std")] fn std_0() {
+assert_eq!((-1.0f64).approx_as_by::<Duration, Secs>(), Err(FloatError::NegOverflow(-1.0)));
+assert_eq!(::std::f64::NAN.approx_as_by::<Duration, Secs>().is_err(), true);
+# }
+# #[cfg(feature = "std")] std_0();
+# }
+```
+*/
+
+use std::marker::PhantomData;
+use std::time::Duration;
+use {ApproxFrom, ApproxScheme, ValueFrom, DefaultApprox, RoundToNearest};
+use errors::{NoError, GeneralError, PosOverflow, RangeError};
+#[cfg(feature = "std")]
+use {RoundToNearestEven, RoundToNegInf, RoundToPosInf, RoundToZero};
+#[cfg(feature = "std")]
+use errors::{FloatError, FloatValueError, Inexact, UnwrapOk};
+
+const NANOS_PER_SEC: u128 = 1_000_000_000;
+
+/**
+This scheme interprets numbers as a count of seconds, rounding to whole nanoseconds (or seconds) using the scheme `R`.
+*/
+pub struct Secs<R = DefaultApprox>(PhantomData<R>);
+impl<R> ApproxScheme for Secs<R> where R: ApproxScheme {}
+
+/**
+This scheme interprets numbers as a count of milliseconds, rounding to whole nanoseconds (or milliseconds) using the scheme `R`.
+*/
+pub struct Millis<R = DefaultApprox>(PhantomData<R>);
+impl<R> ApproxScheme for Millis<R> where R: ApproxScheme {}
+
+/**
+This scheme interprets numbers as a count of microseconds, rounding to whole nanoseconds (or microseconds) using the scheme `R`.
+*/
+pub struct Micros<R = DefaultApprox>(PhantomData<R>);
+impl<R> ApproxScheme for Micros<R> where R: ApproxScheme {}
+
+/**
+This scheme interprets numbers as a count of nanoseconds, rounding to whole nanoseconds using the scheme `R`.
+*/
+pub struct Nanos<R = DefaultApprox>(PhantomData<R>);
+impl<R> ApproxScheme for Nanos<R> where R: ApproxScheme {}
+
+fn total_nanos(d: Duration) -> u128 {
+    d.as_secs() as u128 * NANOS_PER_SEC + d.subsec_nanos() as u128
+}
+
+fn from_total_nanos(n: u128) -> Option<Duration> {
+    let secs = n / NANOS_PER_SEC;
+    if secs > ::std::u64::MAX as u128 {
+        return None;
+    }
+    Some(Duration::new(secs as u64, (n % NANOS_PER_SEC) as u32))
+}
+
+/// Divides `n` by `k`.  Taking `k` as an argument keeps the `Nanos` expansions (where it is one) free of `x % 1`.
+fn div_rem(n: u128, k: u128) -> (u128, u128) {
+    (n / k, n % k)
+}
+
+/**
+Multiplies the finite, non-negative `v` by `k` exactly, returning the whole part of the result, along with the fractional part as a remainder and divisor.
+
+Returns `None` if the whole part is certainly too large for a `Duration`.
+*/
+#[cfg(feature = "std")]
+fn scale_float(v: f64, k: u128) -> Option<(u128, u128, u128)> {
+    // `v` is exactly `m * 2^e`.
+    let bits = v.to_bits();
+    let (m, e) = match ((bits >> 52) & 0x7ff) as i32 {
+        0 => ((bits & ((1 << 52) - 1)) as u128, -1074),
+        exp => ((bits & ((1 << 52) - 1) | 1 << 52) as u128, exp - 1075),
+    };
+    let m = m * k;
+    if m == 0 {
+        return Some((0, 0, 1));
+    }
+    if e >= 0 {
+        // `m` is at least `2^52` here, so shifting it this far puts it beyond `(u64::MAX + 1)` seconds.
+        if e >= 45 {
+            return None;
+        }
+        return Some((m << e, 0, 1));
+    }
+    // `m` is below `2^83`, so past this point the fraction is less than a half, and only matters for being non-zero.
+    let (m, sh) = if e < -100 { ((m != 0) as u128, 100) } else { (m, -e as u32) };
+    Some((m >> sh, m & ((1 << sh) - 1), 1 << sh))
+}
+
+/// Rounds the quotient `$q` of a non-negative division with remainder `$r` by `$k`.
+macro_rules! round_quotient {
+    (DefaultApprox, $q:expr, $r:expr, $k:expr) => { $q };
+    (RoundToZero, $q:expr, $r:expr, $k:expr) => { $q };
+    (RoundToNegInf, $q:expr, $r:expr, $k:expr) => { $q };
+    (RoundToPosInf, $q:expr, $r:expr, $k:expr) => { $q + ($r != 0) as u128 };
+    (RoundToNearest, $q:expr, $r:expr, $k:expr) => { $q + (2 * $r >= $k) as u128 };
+    (RoundToNearestEven, $q:expr, $r:expr, $k:expr) => {
+        $q + (2 * $r > $k || (2 * $r == $k && $q % 2 == 1)) as u128
+    };
+}
+
+macro_rules! time_int {
+    ($unit:ident = $k:expr; $($ity:ident),* $(,)*) => {
+        $(
+            #[allow(unused_comparisons)]
+            impl<R> ApproxFrom<$ity, $unit<R>> for Duration where R: ApproxScheme {
+                type Err = RangeError<$ity>;
+                #[inline]
+                fn approx_from(src: $ity) -> Result<Duration, Self::Err> {
+                    if src < 0 {
+                        return Err(RangeError::NegOverflow(src));
+                    }
+                    (src as u128).checked_mul($k)
+                        .and_then(from_total_nanos)
+                        .ok_or(RangeError::PosOverflow(src))
+                }
+            }
+
+            time_int! { @round $unit = $k; $ity;
+                DefaultApprox, RoundToZero, RoundToNegInf, RoundToPosInf, RoundToNearest, RoundToNearestEven }
+        )*
+    };
+
+    (@round $unit:ident = $k:expr; $ity:ident; $($scheme:ident),*) => {
+        $(
+            impl ApproxFrom<Duration, $unit<::$scheme>> for $ity {
+                type Err = PosOverflow<Duration>;
+                #[inline]
+                #[allow(unused_variables)]
+                fn approx_from(src: Duration) -> Result<$ity, Self::Err> {
+                    let (q, r) = div_rem(total_nanos(src), $k);
+                    <$ity as ValueFrom<u128>>::value_from(round_quotient!($scheme, q, r, $k))
+                        .map_err(|_| PosOverflow(src))
+                }
+            }
+        )*
+    };
+}
+
+/*
+Converting a `Duration` into a float scales the count of nanoseconds up until the quotient has far more bits than the float's mantissa, folding any remainder into the lowest bit.  Converting that to a float rounds correctly, and scaling back down by a power of two is exact.  Because the conversion always rounds to nearest, only `DefaultApprox` and `RoundToNearest` are implemented.
+
+Converting a float into a `Duration` is done exactly, by way of `scale_float`.  Negative values are only in range if they round to zero, so their magnitude is rounded in the opposite direction.
+*/
+macro_rules! time_float {
+    ($unit:ident = $k:expr; $($fty:ident),* $(,)*) => {
+        $(
+            time_float! { @to $unit = $k; $fty; DefaultApprox, RoundToNearest }
+
+            #[cfg(feature = "std")]
+            time_float! { @from $unit = $k; $fty;
+                DefaultApprox / DefaultApprox, RoundToZero / RoundToZero,
+                RoundToNegInf / RoundToPosInf, RoundToPosInf / RoundToNegInf,
+                RoundToNearest / RoundToNearest, RoundToNearestEven / RoundToNearestEven }
+        )*
+    };
+
+    (@to $unit:ident = $k:expr; $fty:ident; $($scheme:ident),*) => {
+        $(
+            impl ApproxFrom<Duration, $unit<$scheme>> for $fty {
+                type Err = NoError;
+                #[inline]
+                fn approx_from(src: Duration) -> Result<$fty, Self::Err> {
+                    let n = total_nanos(src);
+                    if n == 0 {
+                        return Ok(0.0);
+                    }
+                    let s = n.leading_zeros() - 1;
+                    let (q, r) = div_rem(n << s, $k);
+                    Ok((q | (r != 0) as u128) as $fty / (1u128 << s) as $fty)
+                }
+            }
+        )*
+    };
+
+    (@from $unit:ident = $k:expr; $fty:ident; $($scheme:ident / $neg_scheme:ident),*) => {
+        $(
+            impl ApproxFrom<$fty, $unit<$scheme>> for Duration {
+                type Err = FloatError<$fty>;
+                #[inline]
+                #[allow(unused_variables)]
+                fn approx_from(src: $fty) -> Result<Duration, Self::Err> {
+                    if src.is_nan() {
+                        return Err(FloatError::NotANumber(src));
+                    }
+                    if src < 0.0 {
+                        return match scale_float(-(src as f64), $k) {
+                            Some((q, r, d)) if round_quotient!($neg_scheme, q, r, d) == 0 => Ok(Duration::new(0, 0)),
+                            _ => Err(FloatError::NegOverflow(src)),
+                        };
+                    }
+                    scale_float(src as f64, $k)
+                        .and_then(|(q, r, d)| from_total_nanos(round_quotient!($scheme, q, r, d)))
+                        .ok_or(FloatError::PosOverflow(src))
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! time_unit {
+    ($($unit:ident = $k:expr;)*) => {
+        $(
+            time_int! { $unit = $k; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+            time_float! { $unit = $k; f32, f64 }
+        )*
+    };
+}
+
+time_unit! {
+    Secs = NANOS_PER_SEC;
+    Millis = 1_000_000;
+    Micros = 1_000;
+    Nanos = 1;
+}
+
+macro_rules! time_value_int {
+    ($($ity:ident),* $(,)*) => {
+        $(
+            impl ValueFrom<$ity> for Duration {
+                type Err = RangeError<$ity>;
+                #[inline]
+                fn value_from(src: $ity) -> Result<Duration, Self::Err> {
+                    <Duration as ApproxFrom<$ity, Secs>>::approx_from(src)
+                }
+            }
+
+            impl ValueFrom<Duration> for $ity {
+                type Err = GeneralError<Duration>;
+                #[inline]
+                fn value_from(src: Duration) -> Result<$ity, Self::Err> {
+                    if src.subsec_nanos() != 0 {
+                        return Err(GeneralError::Unrepresentable(src));
+                    }
+                    <$ity as ValueFrom<u64>>::value_from(src.as_secs())
+                        .map_err(|_| GeneralError::PosOverflow(src))
+                }
+            }
+        )*
+    };
+}
+
+time_value_int! { i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize }
+
+#[cfg(feature = "std")]
+macro_rules! time_value_float {
+    ($($fty:ident),* $(,)*) => {
+        $(
+            impl ValueFrom<$fty> for Duration {
+                type Err = FloatValueError<$fty>;
+                #[inline]
+                fn value_from(src: $fty) -> Result<Duration, Self::Err> {
+                    if src.is_nan() {
+                        return Err(FloatValueError::NotANumber(src));
+                    }
+                    if src < 0.0 {
+                        return Err(FloatValueError::NegOverflow(src));
+                    }
+                    match scale_float(src as f64, NANOS_PER_SEC) {
+                        Some((n, 0, _)) => from_total_nanos(n).ok_or(FloatValueError::PosOverflow(src)),
+                        Some(_) => Err(FloatValueError::Inexact(src)),
+                        None => Err(FloatValueError::PosOverflow(src)),
+                    }
+                }
+            }
+
+            impl ValueFrom<Duration> for $fty {
+                type Err = Inexact<Duration>;
+                #[inline]
+                fn value_from(src: Duration) -> Result<$fty, Self::Err> {
+                    let v = <$fty as ApproxFrom<Duration, Secs>>::approx_from(src).unwrap_ok();
+                    match <Duration as ValueFrom<$fty>>::value_from(v) {
+                        Ok(d) if d == src => Ok(v),
+                        _ => Err(Inexact(src)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "std")]
+time_value_float! { f32, f64 }
@@ -0,0 +1,74 @@
+extern crate conv;
+
+use conv::*;
+use conv::bulk::*;
+
+#[test]
+fn test_into_slice() {
+    let mut dst = [0u8; 4];
+    assert_eq!(value_into_slice(&[1i32, 2, 3, 4], &mut dst), Ok(()));
+    assert_eq!(dst, [1, 2, 3, 4]);
+
+    let err = value_into_slice(&[5i32, 6, 256, -1], &mut dst).unwrap_err();
+    assert_eq!(err.index(), 2);
+    assert_eq!(*err.error(), RangeError::PosOverflow(256));
+    assert_eq!(err.to_string(), "element 2: conversion resulted in positive overflow");
+    assert_eq!(dst[..2], [5, 6]);
+
+    let mut dst = [0u8; 2];
+    assert_eq!(approx_into_slice::<_, _, Wrapping>(&[256u16, 257], &mut dst), Ok(()));
+    assert_eq!(dst, [0, 1]);
+    assert_eq!(approx_into_slice::<_, _, DefaultApprox>(&[1u16, 257], &mut dst),
+        Err(bulk::IndexedError::new(1, PosOverflow(257u16))));
+}
+
+#[test]
+#[should_panic]
+fn test_into_slice_length_mismatch() {
+    let mut dst = [0u8; 2];
+    let _ = value_into_slice(&[1i32, 2, 3], &mut dst);
+}
+
+#[test]
+fn test_into_slice_never_fails() {
+    let mut dst = [0i8; 4];
+    value_into_slice_or_saturate(&[-300i32, -5, 5, 300], &mut dst);
+    assert_eq!(dst, [-128, -5, 5, 127]);
+
+    let mut dst = [0u8; 3];
+    approx_into_slice_or_saturate::<_, _, DefaultApprox>(&[-1i16, 1, 256], &mut dst);
+    assert_eq!(dst, [0, 1, 255]);
+
+    let mut dst = [Some(0u8); 3];
+    value_into_slice_or_invalid(&[Some(1u8), Some(2), Some(3)], &mut dst);
+    assert_eq!(dst, [Some(1), Some(2), Some(3)]);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_from_slice() {
+    assert_eq!(value_from_slice::<_, u16>(&[1u8, 2, 3]), Ok(vec![1u16, 2, 3]));
+    assert_eq!(value_from_slice::<_, u16>(&[1i32, -2, -3]),
+        Err(bulk::IndexedError::new(1, RangeError::NegOverflow(-2))));
+
+    let samples = [0.4f64, 1.5, -2.5, 40000.0, ::std::f64::NAN];
+    assert_eq!(approx_from_slice::<_, i16, RoundToNearest>(&samples[..3]), Ok(vec![0, 2, -3]));
+    let err = approx_from_slice::<_, i16, RoundToNearest>(&samples).unwrap_err();
+    assert_eq!((err.index(), err.into_inner()), (3, FloatError::PosOverflow(40000.0)));
+
+    let mut dst = [0.0f32; 5];
+    value_into_slice_or_invalid(&samples, &mut dst);
+    assert!(dst[0].is_nan());
+    assert_eq!(dst[1..4], [1.5, -2.5, 40000.0]);
+    assert!(dst[4].is_nan());
+}
+
+#[cfg(feature = "const_generics")]
+#[test]
+fn test_from_array() {
+    assert_eq!(value_from_array::<_, u8, 3>([1i32, 2, 3]), Ok([1u8, 2, 3]));
+    assert_eq!(value_from_array::<_, u8, 3>([1i32, 256, -1]),
+        Err(bulk::IndexedError::new(1, RangeError::PosOverflow(256))));
+    assert_eq!(approx_from_array::<_, u8, Saturating, 2>([-1i32, 256]), Ok([0u8, 255]));
+    assert_eq!(approx_from_array::<_, i8, RoundToNearest, 0>([0.0f32; 0]), Ok([]));
+}
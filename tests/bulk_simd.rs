@@ -0,0 +1,128 @@
+#![cfg(all(feature = "simd", feature = "std"))]
+
+extern crate conv;
+
+use std::f32::{INFINITY, NAN, NEG_INFINITY, MIN_POSITIVE};
+use conv::*;
+use conv::bulk;
+use conv::bulk::simd;
+
+const EDGES: &'static [f32] = &[
+    0.0, -0.0, 0.5, -0.5, 0.49999997, -0.49999997, 1.5, -1.5, 2.5, -2.5,
+    32767.0, 32767.49, 32767.5, 32767.9, 32768.0, -32768.0, -32768.49, -32768.5, -32768.9, -32769.0,
+    1e9, -1e9, INFINITY, NEG_INFINITY, NAN, MIN_POSITIVE,
+];
+
+/// A small xorshift generator, so that the tests are reproducible.
+struct Rng(u32);
+
+impl Rng {
+    fn next(&mut self) -> u32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0
+    }
+
+    fn sample(&mut self) -> f32 {
+        (self.next() as i32 as f32) / 65536.0
+    }
+}
+
+/// Inputs with valid values only, of lengths which aren't all multiples of the vector width.
+fn valid_inputs() -> Vec<Vec<f32>> {
+    let mut rng = Rng(0x2545_f491);
+    (0..70).map(|len| (0..len).map(|_| rng.sample()).collect()).collect()
+}
+
+/// Inputs with each edge case placed at various positions in otherwise valid data.
+fn edge_inputs() -> Vec<Vec<f32>> {
+    let mut rng = Rng(0x9e37_79b9);
+    let mut inputs = vec![];
+    for &edge in EDGES {
+        for &len in &[1, 7, 8, 16, 17, 33, 64] {
+            for pos in 0..len {
+                let mut v: Vec<f32> = (0..len).map(|_| rng.sample()).collect();
+                v[pos] = edge;
+                inputs.push(v);
+            }
+        }
+    }
+    inputs
+}
+
+macro_rules! check_same {
+    ($src:expr, $dst:ty, $scalar:expr, $simd:expr) => {
+        {
+            let src = $src;
+            let mut expected = vec![<$dst>::default(); src.len()];
+            let mut actual = vec![<$dst>::default(); src.len()];
+            let e = $scalar(&src[..], &mut expected[..]);
+            let a = $simd(&src[..], &mut actual[..]);
+            // NaN payloads don't compare equal, so compare the debug representation instead.
+            assert_eq!(format!("{:?}", a), format!("{:?}", e), "input: {:?}", src);
+            assert_eq!(
+                actual.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+                expected.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+                "input: {:?}", src);
+        }
+    };
+}
+
+trait ToBits { type Bits: PartialEq + ::std::fmt::Debug; fn to_bits(&self) -> Self::Bits; }
+impl ToBits for i16 { type Bits = i16; fn to_bits(&self) -> i16 { *self } }
+impl ToBits for f32 { type Bits = u32; fn to_bits(&self) -> u32 { f32::to_bits(*self) } }
+
+#[test]
+fn test_f32_to_i16() {
+    for src in valid_inputs().into_iter().chain(edge_inputs()) {
+        check_same!(src.clone(), i16,
+            bulk::approx_into_slice::<f32, i16, DefaultApprox>,
+            simd::approx_into_slice::<f32, i16, DefaultApprox>);
+        check_same!(src.clone(), i16,
+            bulk::approx_into_slice::<f32, i16, RoundToNearest>,
+            simd::approx_into_slice::<f32, i16, RoundToNearest>);
+        check_same!(src, i16,
+            bulk::approx_into_slice::<f32, i16, Saturating>,
+            simd::approx_into_slice::<f32, i16, Saturating>);
+    }
+}
+
+#[test]
+fn test_f32_to_i16_errors() {
+    let mut src = vec![1.0f32; 40];
+    src[21] = 32767.5;
+    src[30] = NAN;
+    let mut dst = vec![0i16; 40];
+
+    let err = simd::approx_into_slice::<_, _, RoundToNearest>(&src, &mut dst).unwrap_err();
+    assert_eq!((err.index(), err.into_inner()), (21, FloatError::PosOverflow(32767.5)));
+    assert!(dst[..21].iter().all(|&v| v == 1));
+
+    let err = simd::approx_into_slice::<_, _, Saturating>(&src, &mut dst).unwrap_err();
+    assert_eq!(err.index(), 30);
+    assert_eq!(dst[21], 32767);
+}
+
+#[test]
+fn test_int_to_f32() {
+    let mut rng = Rng(0x1234_5678);
+    for len in 0..70 {
+        let ints: Vec<i16> = (0..len).map(|_| rng.next() as i16).collect();
+        check_same!(ints, f32, bulk::value_into_slice::<i16, f32>, simd::value_into_slice::<i16, f32>);
+        let bytes: Vec<u8> = (0..len).map(|_| rng.next() as u8).collect();
+        check_same!(bytes, f32, bulk::value_into_slice::<u8, f32>, simd::value_into_slice::<u8, f32>);
+    }
+
+    let ints = [i16::min_value(), -1, 0, 1, i16::max_value(), 0, 0, 0, 0];
+    let mut dst = [0.0f32; 9];
+    simd::value_into_slice(&ints, &mut dst).unwrap();
+    assert_eq!(dst, [-32768.0, -1.0, 0.0, 1.0, 32767.0, 0.0, 0.0, 0.0, 0.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_length_mismatch() {
+    let mut dst = [0i16; 15];
+    let _ = simd::approx_into_slice::<_, _, RoundToNearest>(&[0.0f32; 16], &mut dst);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "core_convert")]
+#[macro_use] extern crate conv;
+
+use conv::prelude::*;
+use conv::{TryFrom, Unrepresentable};
+use conv::compat::{Conv, FromConv, FromStd};
+
+#[derive(Debug, PartialEq)]
+enum Get { Up, Down, AllAround }
+
+TryFrom! { (u8) enum Get { Up, Down, AllAround } }
+
+fn std_try<T, Src>(src: Src) -> Result<T, T::Error>
+where T: std::convert::TryFrom<Src> {
+    T::try_from(src)
+}
+
+fn conv_try<T, Src>(src: Src) -> Result<T, T::Err>
+where T: conv::TryFrom<Src> {
+    T::try_from(src)
+}
+
+#[test]
+fn test_from_std() {
+    assert_eq!(conv_try::<FromStd<u8, _>, _>(255i32).map(FromStd::into_inner), Ok(255u8));
+    assert!(conv_try::<FromStd<u8, _>, _>(256i32).is_err());
+    assert!(conv_try::<FromStd<u8, _>, _>(-1i32).is_err());
+    assert_eq!(FromStd::<char, _>::try_from(0x41u32).map(FromStd::into_inner), Ok('A'));
+    assert!(FromStd::<char, _>::try_from(0xd800u32).is_err());
+}
+
+#[test]
+fn test_from_conv() {
+    assert_eq!(std_try::<FromConv<Get>, _>(Conv(1u8)), Ok(FromConv(Get::Down)));
+    assert_eq!(std_try::<FromConv<Get>, _>(Conv(3u8)), Err(Unrepresentable(3u8)));
+
+    let v: Result<FromConv<Get>, _> = std::convert::TryInto::try_into(Conv(2u8));
+    assert_eq!(v.map(FromConv::into_inner), Ok(Get::AllAround));
+}
+
+#[test]
+fn test_try_as_std() {
+    assert_eq!(300i32.try_as_std::<u16>(), Ok(300));
+    assert!(300i32.try_as_std::<u8>().is_err());
+    assert_eq!(7u8.try_as::<Get>(), Err(Unrepresentable(7u8)));
+    assert_eq!(2u8.try_as::<Get>(), Ok(Get::AllAround));
+}
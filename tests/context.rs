@@ -0,0 +1,70 @@
+#![cfg(feature = "context")]
+#[macro_use] extern crate conv;
+
+use conv::prelude::*;
+use conv::{NoError, RangeErrorKind, TryFrom, Unrepresentable};
+use conv::context::{ContextType, ConvError, ResultContext};
+
+#[derive(Debug, PartialEq)]
+enum Get { Up, Down, AllAround }
+
+TryFrom! { (u8) enum Get { Up, Down, AllAround } }
+
+impl ContextType for Get {}
+
+macro_rules! check_ctx {
+    ($e:expr, $msg:expr) => {
+        assert_eq!($e.context().unwrap_err().to_string(), $msg)
+    };
+}
+
+#[test]
+fn test_context() {
+    check_ctx!(300i32.value_as::<u8>(), "300i32 → u8: positive overflow (max 255)");
+    check_ctx!((-300i32).value_as::<i8>(), "-300i32 → i8: negative overflow (min -128)");
+    check_ctx!((-1i8).value_as::<u64>(), "-1i8 → u64: negative overflow (min 0)");
+    check_ctx!(70_000u32.value_as::<u16>(), "70000u32 → u16: positive overflow (max 65535)");
+    check_ctx!(3u8.try_as::<Get>(), "3u8 → context::Get: unrepresentable");
+    check_ctx!('\u{100}'.try_as::<u8>(), "Ā (char) → u8: positive overflow (max 255)");
+
+    let general: Result<u8, conv::GeneralError<i32>> = 300i32.value_as::<u8>().map_err(From::from);
+    check_ctx!(general, "300i32 → u8: positive overflow (max 255)");
+
+    let kind: Result<u8, RangeErrorKind> = 300i32.value_as::<u8>().map_err(From::from);
+    check_ctx!(kind, "conversion to u8: positive overflow (max 255)");
+
+    check_ctx!(Err::<isize, _>(conv::NegOverflow(-1e19f64)), format!("-10000000000000000000f64 → isize: negative overflow (min {})", isize::min_value()));
+    check_ctx!(Err::<usize, _>(conv::PosOverflow(1e20f64)), format!("100000000000000000000f64 → usize: positive overflow (max {})", usize::max_value()));
+    check_ctx!(Err::<i128, _>(conv::PosOverflow(1e20f64)), format!("100000000000000000000f64 → i128: positive overflow (max {})", i128::max_value()));
+    check_ctx!(Err::<u128, _>(conv::NegOverflow(-1i8)), "-1i8 → u128: negative overflow (min 0)");
+    check_ctx!(Err::<f64, _>(conv::NegOverflow(-1.5f64)), format!("-1.5f64 → f64: negative overflow (min {:e})", ::std::f64::MIN));
+    check_ctx!(Err::<Get, _>(conv::NegOverflow(-1i8)), "-1i8 → context::Get: negative overflow");
+
+    assert_eq!(1u8.try_as::<Get>().context(), Ok(Get::Down));
+    assert_eq!(7u8.value_as::<u16>().context(), Ok::<_, ConvError<NoError>>(7));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_context_floats() {
+    check_ctx!(256.0f32.approx_as::<u8>(), "256f32 → u8: positive overflow (max 255)");
+    check_ctx!(::std::f32::NAN.approx_as::<u8>(), "NaNf32 → u8: not a number");
+    check_ctx!(2.5f64.value_as::<i32>(), "2.5f64 → i32: inexact");
+    check_ctx!(1e40f64.approx_as::<f32>(), "10000000000000000000000000000000000000000f64 → f32: positive overflow (max 3.4028235e38)");
+    check_ctx!(0.1f64.value_as::<f32>(), "0.1f64 → f32: inexact");
+}
+
+#[test]
+fn test_context_accessors() {
+    let err = 3u8.try_as::<Get>().context().unwrap_err();
+    assert_eq!(err.dst_type(), "context::Get");
+    assert_eq!(*err.error(), Unrepresentable(3u8));
+    assert_eq!(err.into_inner(), Unrepresentable(3u8));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_context_source() {
+    let err: Box<::std::error::Error> = Box::new(300i32.value_as::<u8>().context().unwrap_err());
+    assert!(err.source().is_some());
+}
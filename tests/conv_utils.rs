@@ -40,3 +40,23 @@ fn test_whizzo() {
     assert_eq!((-1.0f32).approx_as::<u8>().saturate(), Ok::<_, Unrepresentable<_>>(0u8));
     assert_eq!((-1i32).value_as::<u8>().saturate().unwrap_ok(), 0u8);
 }
+
+#[test]
+fn test_saturate_narrow_errors() {
+    use conv::errors::{GeneralError, GeneralErrorKind, NegOverflow, PosOverflow, Unrepresentable};
+    assert_eq!(u8::value_from(-1i8).saturate().unwrap_ok(), 0u8);
+    assert_eq!(u8::value_from(5i8).saturate().unwrap_ok(), 5u8);
+    assert_eq!(i8::value_from(200u8).saturate().unwrap_ok(), 127i8);
+    assert_eq!(Err::<u8, _>(NegOverflow(-3i32)).saturate().unwrap_ok(), 0u8);
+    assert_eq!(Err::<u8, _>(PosOverflow(300i32)).saturate().unwrap_ok(), 255u8);
+
+    assert_eq!(Err::<i8, _>(GeneralError::NegOverflow(-200i32)).saturate(), Ok(-128i8));
+    assert_eq!(Err::<i8, _>(GeneralError::PosOverflow(200i32)).saturate(), Ok(127i8));
+    assert_eq!(Err::<i8, _>(GeneralError::Unrepresentable(1i32)).saturate(), Err(Unrepresentable(1i32)));
+    assert_eq!(Err::<i8, _>(GeneralError::NotANumber(2i32)).saturate(), Err(Unrepresentable(2i32)));
+
+    assert_eq!(Err::<u16, _>(GeneralErrorKind::NegOverflow).saturate(), Ok(0u16));
+    assert_eq!(Err::<u16, _>(GeneralErrorKind::PosOverflow).saturate(), Ok(65_535u16));
+    assert_eq!(Err::<u16, _>(GeneralErrorKind::NotANumber).saturate(), Err(GeneralErrorKind::NotANumber));
+    assert_eq!(Err::<u16, _>(GeneralErrorKind::Unrepresentable).saturate(), Err(GeneralErrorKind::Unrepresentable));
+}
@@ -0,0 +1,61 @@
+#[macro_use] extern crate conv;
+
+use conv::*;
+
+#[derive(Debug, PartialEq)]
+struct Percent(u8);
+
+Ranged! { (0, 100) struct Percent(u8); }
+
+#[derive(Debug, PartialEq)]
+pub struct Port(pub u16);
+
+Ranged! { (1, 65_535) pub struct Port(pub u16); }
+
+#[derive(Debug, PartialEq)]
+struct Offset(i16);
+
+Ranged! { (-1_000, 1_000) struct Offset(i16); }
+
+#[test]
+fn test_ranged() {
+    assert_eq!(Percent::value_from(0u8), Ok(Percent(0)));
+    assert_eq!(Percent::value_from(100u8), Ok(Percent(100)));
+    assert_eq!(Percent::value_from(101u8), Err(RangeError::PosOverflow(101)));
+    assert_eq!(Percent::value_from(255u8), Err(RangeError::PosOverflow(255)));
+    assert_eq!(Percent::value_from(-1i8), Err(RangeError::NegOverflow(-1)));
+    assert_eq!(Percent::value_from(50i64), Ok(Percent(50)));
+    assert_eq!(Percent::value_from(-50_000i64), Err(RangeError::NegOverflow(-50_000)));
+    assert_eq!(Percent::value_from(50_000u128), Err(RangeError::PosOverflow(50_000)));
+    assert_eq!(Percent::try_from(99usize), Ok(Percent(99)));
+    assert_eq!(Percent::try_from(101isize), Err(RangeError::PosOverflow(101)));
+    assert_eq!(100i32.value_into(), Ok(Percent(100)));
+
+    assert_eq!(Port::value_from(0u16), Err(RangeError::NegOverflow(0)));
+    assert_eq!(Port::value_from(1u16), Ok(Port(1)));
+    assert_eq!(Port::value_from(65_535u32), Ok(Port(65_535)));
+    assert_eq!(Port::value_from(65_536u32), Err(RangeError::PosOverflow(65_536)));
+    assert_eq!(Port::value_from(-80i32), Err(RangeError::NegOverflow(-80)));
+
+    assert_eq!(Offset::value_from(-1_000i32), Ok(Offset(-1_000)));
+    assert_eq!(Offset::value_from(-1_001i32), Err(RangeError::NegOverflow(-1_001)));
+    assert_eq!(Offset::value_from(1_001u64), Err(RangeError::PosOverflow(1_001)));
+    assert_eq!(Offset::value_from(40_000u16), Err(RangeError::PosOverflow(40_000)));
+    assert_eq!(Offset::value_from(-40_000i32), Err(RangeError::NegOverflow(-40_000)));
+}
+
+#[test]
+fn test_ranged_saturate() {
+    assert_eq!(Percent::value_from(250u8).unwrap_or_saturate(), Percent(100));
+    assert_eq!(Percent::value_from(-3i32).unwrap_or_saturate(), Percent(0));
+    assert_eq!(Port::value_from(0u8).unwrap_or_saturate(), Port(1));
+    assert_eq!(Offset::value_from(i64::min_value()).unwrap_or_saturate(), Offset(-1_000));
+    assert_eq!(Offset::value_from(u64::max_value()).unwrap_or_saturate(), Offset(1_000));
+}
+
+#[test]
+fn test_ranged_inner() {
+    assert_eq!(u8::value_from(Percent(42)).unwrap_ok(), 42);
+    assert_eq!(u16::value_from(Port(8080)).unwrap_ok(), 8080);
+    assert_eq!(i16::value_from(Offset(-7)).unwrap_ok(), -7);
+}
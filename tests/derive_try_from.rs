@@ -43,3 +43,130 @@ fn test_try_from() {
     assert_eq!(GottaGo::try_from(9001u16), Ok(GottaGo::Faster));
     assert_eq!(GottaGo::try_from(9002u16), Err(Unrepresentable(9002u16)));
 }
+
+#[derive(Debug, PartialEq)]
+#[repr(u8)]
+enum Opcode { Nop, Load = 0x10, Store, Unknown(u8), Halt = 0xff }
+
+TryFrom! { (u8)
+    pub enum Opcode {
+        Nop,
+        Load = 0x10,
+        Store,
+        /// Anything else.
+        #[conv(other)]
+        Unknown(u8),
+        Halt = 0xff,
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[repr(i32)]
+enum Sparse { Unknown(i32), A = 3, B }
+
+TryFrom! { (i32)
+    enum Sparse {
+        #[conv(other)]
+        /// Anything else.
+        Unknown(i32),
+        A = 3,
+        B
+    }
+}
+
+#[derive(Debug, PartialEq)]
+#[repr(u64)]
+enum Magic { Elf = 0x7f45_4c46, Unknown(u64), Huge = 0xffff_ffff_ffff_fff0, AfterHuge }
+
+TryFrom! { (u64)
+    enum Magic {
+        Elf = 0x7f45_4c46,
+        #[conv(other)]
+        Unknown(u64),
+        Huge = 0xffff_ffff_ffff_fff0,
+        AfterHuge,
+    }
+}
+
+#[test]
+fn test_try_from_other() {
+    use conv::{NoError, UnwrapOk};
+
+    let _: Result<Opcode, NoError> = Opcode::try_from(0u8);
+
+    assert_eq!(Opcode::try_from(0x00u8).unwrap_ok(), Opcode::Nop);
+    assert_eq!(Opcode::try_from(0x01u8).unwrap_ok(), Opcode::Unknown(0x01));
+    assert_eq!(Opcode::try_from(0x10u8).unwrap_ok(), Opcode::Load);
+    assert_eq!(Opcode::try_from(0x11u8).unwrap_ok(), Opcode::Store);
+    assert_eq!(Opcode::try_from(0x12u8).unwrap_ok(), Opcode::Unknown(0x12));
+    assert_eq!(Opcode::try_from(0xfeu8).unwrap_ok(), Opcode::Unknown(0xfe));
+    assert_eq!(Opcode::try_from(0xffu8).unwrap_ok(), Opcode::Halt);
+
+    assert_eq!(Sparse::try_from(0i32).unwrap_ok(), Sparse::Unknown(0));
+    assert_eq!(Sparse::try_from(-1i32).unwrap_ok(), Sparse::Unknown(-1));
+    assert_eq!(Sparse::try_from(3i32).unwrap_ok(), Sparse::A);
+    assert_eq!(Sparse::try_from(4i32).unwrap_ok(), Sparse::B);
+    assert_eq!(Sparse::try_from(5i32).unwrap_ok(), Sparse::Unknown(5));
+
+    assert_eq!(Magic::try_from(0x7f45_4c46u64).unwrap_ok(), Magic::Elf);
+    assert_eq!(Magic::try_from(0xffff_ffff_ffff_fff0u64).unwrap_ok(), Magic::Huge);
+    assert_eq!(Magic::try_from(0xffff_ffff_ffff_fff1u64).unwrap_ok(), Magic::AfterHuge);
+    assert_eq!(Magic::try_from(0u64).unwrap_ok(), Magic::Unknown(0));
+}
+
+#[derive(Debug, PartialEq)]
+enum Colour { Red, Green, Grey }
+
+TryFrom! { (&str)
+    enum Colour {
+        Red,
+        /// Not blue.
+        Green,
+        #[conv(alias = "Gray")]
+        #[conv(alias = "Silver")]
+        Grey
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Flag { Verbose = 1, Quiet = 2 }
+
+TryFrom! { (&str, ignore_case)
+    pub enum Flag {
+        #[conv(alias = "v")]
+        Verbose = 1,
+        #[conv(alias = "q")]
+        /// Shh.
+        Quiet = 2,
+    }
+}
+
+#[test]
+fn test_try_from_str() {
+    use conv::{ValueFrom, UnwrapOk};
+
+    assert_eq!(Colour::try_from("Red"), Ok(Colour::Red));
+    assert_eq!(Colour::try_from("Green"), Ok(Colour::Green));
+    assert_eq!(Colour::try_from("Grey"), Ok(Colour::Grey));
+    assert_eq!(Colour::try_from("Gray"), Ok(Colour::Grey));
+    assert_eq!(Colour::try_from("Silver"), Ok(Colour::Grey));
+    assert_eq!(Colour::try_from("red"), Err(Unrepresentable("red".to_string())));
+    assert_eq!(Colour::try_from(""), Err(Unrepresentable("".to_string())));
+
+    assert_eq!(<&str>::value_from(&Colour::Red).unwrap_ok(), "Red");
+    assert_eq!(<&str>::value_from(Colour::Grey).unwrap_ok(), "Grey");
+    assert_eq!(Colour::Green.to_string(), "Green");
+    assert_eq!(format!("{}", Colour::Grey), "Grey");
+
+    assert_eq!(Flag::try_from("Verbose"), Ok(Flag::Verbose));
+    assert_eq!(Flag::try_from("VERBOSE"), Ok(Flag::Verbose));
+    assert_eq!(Flag::try_from("v"), Ok(Flag::Verbose));
+    assert_eq!(Flag::try_from("Q"), Ok(Flag::Quiet));
+    assert_eq!(Flag::try_from("quiet"), Ok(Flag::Quiet));
+    assert_eq!(Flag::try_from("loud"), Err(Unrepresentable("loud".to_string())));
+    assert_eq!(Flag::Quiet.to_string(), "Quiet");
+
+    for name in &["Red", "Green", "Grey"] {
+        assert_eq!(Colour::try_from(*name).unwrap().to_string(), *name);
+    }
+}
@@ -0,0 +1,63 @@
+#[macro_use] extern crate conv;
+
+use conv::{TryFrom, ValueFrom, UnwrapOk};
+
+#[derive(Debug, PartialEq)]
+enum Get { Up, Down, AllAround }
+
+TryFrom! { (u8) enum Get { Up, Down, AllAround } }
+ValueFrom! { (u8)
+    enum Get {
+        Up,
+        /// And
+        Down,
+        /** And */
+        AllAround
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Sign { Neg = -1, Zero = 0, Pos = 1 }
+
+ValueFrom! { (i8) pub enum Sign { Neg = -1, Zero = 0, Pos = 1 } }
+
+#[derive(Debug, PartialEq)]
+enum GottaGo { GetAway, Fast = 9000, Faster = 9001 }
+
+ValueFrom! { (u16)
+    enum GottaGo {
+        GetAway,
+        Fast = 9000,
+        /// This show was stupid.
+        Faster = 9001
+    }
+}
+
+#[test]
+fn test_value_from() {
+    assert_eq!(u8::value_from(Get::Up), Ok(0));
+    assert_eq!(u8::value_from(Get::Down), Ok(1));
+    assert_eq!(u8::value_from(Get::AllAround), Ok(2));
+    assert_eq!(u16::value_from(Get::AllAround).unwrap_ok(), 2);
+    assert_eq!(i16::value_from(Get::AllAround).unwrap_ok(), 2);
+    assert_eq!(u128::value_from(Get::AllAround).unwrap_ok(), 2);
+    assert_eq!(usize::value_from(Get::AllAround).unwrap_ok(), 2);
+    assert_eq!(isize::value_from(Get::AllAround).unwrap_ok(), 2);
+
+    assert_eq!(Get::try_from(u8::value_from(Get::Up).unwrap_ok()), Ok(Get::Up));
+    assert_eq!(Get::try_from(u8::value_from(Get::Down).unwrap_ok()), Ok(Get::Down));
+    assert_eq!(Get::try_from(u8::value_from(Get::AllAround).unwrap_ok()), Ok(Get::AllAround));
+
+    assert_eq!(i8::value_from(Sign::Neg), Ok(-1));
+    assert_eq!(i16::value_from(Sign::Neg), Ok(-1));
+    assert_eq!(i64::value_from(Sign::Neg), Ok(-1));
+    assert_eq!(isize::value_from(Sign::Neg), Ok(-1));
+    assert_eq!(i128::value_from(Sign::Pos), Ok(1));
+    assert_eq!(i32::value_from(Sign::Zero), Ok(0));
+
+    assert_eq!(u16::value_from(GottaGo::GetAway), Ok(0));
+    assert_eq!(u16::value_from(GottaGo::Fast), Ok(9000));
+    assert_eq!(u32::value_from(GottaGo::Faster), Ok(9001));
+    assert_eq!(i32::value_from(GottaGo::Faster), Ok(9001));
+    assert_eq!(usize::value_from(GottaGo::Faster), Ok(9001));
+}
@@ -0,0 +1,81 @@
+extern crate conv;
+
+use conv::prelude::*;
+use conv::{
+    GeneralError, GeneralErrorKind, Unrepresentable, Inexact,
+    NegOverflow, PosOverflow,
+    FloatError, FloatErrorKind, FloatValueError, RangeError, RangeErrorKind,
+    Verbose,
+};
+
+struct Opaque;
+
+macro_rules! check_debug {
+    ($e:expr, $dummy:expr, $verbose:expr) => {
+        assert_eq!(format!("{:?}", $e), $dummy);
+        assert_eq!(format!("{:?}", Verbose($e)), $verbose);
+    };
+}
+
+#[test]
+fn test_verbose_debug() {
+    check_debug!(NegOverflow(-1i8), "NegOverflow(..)", "NegOverflow(-1)");
+    check_debug!(PosOverflow(300i32), "PosOverflow(..)", "PosOverflow(300)");
+    check_debug!(Unrepresentable('x'), "Unrepresentable(..)", "Unrepresentable('x')");
+    check_debug!(Inexact(0.5f64), "Inexact(..)", "Inexact(0.5)");
+    check_debug!(FloatError::NotANumber(::std::f32::NAN), "FloatError::NotANumber(..)", "FloatError::NotANumber(NaN)");
+    check_debug!(FloatValueError::Inexact(2.5f64), "FloatValueError::Inexact(..)", "FloatValueError::Inexact(2.5)");
+    check_debug!(RangeError::PosOverflow(300i32), "RangeError::PosOverflow(..)", "RangeError::PosOverflow(300)");
+    check_debug!(RangeError::NegOverflow(-300i32), "RangeError::NegOverflow(..)", "RangeError::NegOverflow(-300)");
+    check_debug!(GeneralError::Unrepresentable("abc"), "GeneralError::Unrepresentable(..)", "GeneralError::Unrepresentable(\"abc\")");
+    check_debug!(GeneralErrorKind::PosOverflow, "PosOverflow", "PosOverflow");
+    check_debug!(RangeErrorKind::NegOverflow, "NegOverflow", "NegOverflow");
+    check_debug!(FloatErrorKind::NotANumber, "NotANumber", "NotANumber");
+    check_debug!(GeneralError::NotANumber(1.5f32), "GeneralError::NotANumber(..)", "GeneralError::NotANumber(1.5)");
+
+    assert_eq!(format!("{:?}", Unrepresentable(Opaque)), "Unrepresentable(..)");
+}
+
+#[test]
+fn test_verbose_forwarding() {
+    let err = 300i32.value_as::<u8>().map_err(Verbose).unwrap_err();
+    assert_eq!(err.to_string(), RangeError::PosOverflow(300i32).to_string());
+    assert_eq!(err, Verbose(RangeError::PosOverflow(300i32)));
+    assert_eq!(err.into_inner(), RangeError::PosOverflow(300i32));
+
+    let err: Box<::std::error::Error> = Box::new(Verbose(PosOverflow(300i32)));
+    assert_eq!(format!("{:?}", err), "PosOverflow(300)");
+
+    let err: Verbose<NegOverflow<i8>> = NegOverflow(-1i8).into();
+    assert_eq!(format!("{:#?}", err), "NegOverflow(\n    -1,\n)");
+}
+
+#[test]
+fn test_not_a_number() {
+    use std::f32::NAN;
+
+    fn general<E: Into<GeneralError<f32>>>(e: E) -> GeneralError<f32> { e.into() }
+    fn general_kind<E: Into<GeneralErrorKind>>(e: E) -> GeneralErrorKind { e.into() }
+    fn float_kind<E: Into<FloatErrorKind>>(e: E) -> FloatErrorKind { e.into() }
+
+    let err = NAN.approx_as::<u8>().unwrap_err();
+    assert!(match general(err) { GeneralError::NotANumber(v) => v.is_nan(), _ => false });
+    assert_eq!(general_kind(err), GeneralErrorKind::NotANumber);
+    assert_eq!(float_kind(err), FloatErrorKind::NotANumber);
+
+    let err = NAN.value_as::<u8>().unwrap_err();
+    assert!(match general(err) { GeneralError::NotANumber(v) => v.is_nan(), _ => false });
+    assert_eq!(general_kind(err), GeneralErrorKind::NotANumber);
+
+    assert_eq!(general(FloatError::PosOverflow(1e10)), GeneralError::PosOverflow(1e10));
+    assert_eq!(general(FloatValueError::Inexact(0.5)), GeneralError::Unrepresentable(0.5));
+    assert_eq!(general_kind(FloatValueError::Inexact(0.5f32)), GeneralErrorKind::Unrepresentable);
+    assert_eq!(general_kind(GeneralError::NotANumber(NAN)), GeneralErrorKind::NotANumber);
+
+    assert_eq!(float_kind(NegOverflow(-1.0f32)), FloatErrorKind::NegOverflow);
+    assert_eq!(float_kind(PosOverflow(1e10f32)), FloatErrorKind::PosOverflow);
+    assert_eq!(float_kind(RangeError::PosOverflow(1e10f32)), FloatErrorKind::PosOverflow);
+    assert_eq!(float_kind(RangeErrorKind::NegOverflow), FloatErrorKind::NegOverflow);
+    assert_eq!(general_kind(FloatErrorKind::NotANumber), GeneralErrorKind::NotANumber);
+    assert_eq!(general_kind(FloatErrorKind::PosOverflow), GeneralErrorKind::PosOverflow);
+}
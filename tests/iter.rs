@@ -0,0 +1,68 @@
+extern crate conv;
+
+use conv::*;
+use conv::bulk::IndexedError;
+
+#[test]
+fn test_value_into_each() {
+    let v: Vec<_> = vec![1i32, -1, 256].into_iter().value_into_each::<u8>().collect();
+    assert_eq!(v, [Ok(1), Err(RangeError::NegOverflow(-1)), Err(RangeError::PosOverflow(256))]);
+
+    let it = [1u8, 2, 3].iter().cloned().value_into_each::<u16>();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.map(UnwrapOk::unwrap_ok).collect::<Vec<_>>(), [1, 2, 3]);
+}
+
+#[test]
+fn test_approx_into_each() {
+    let v: Vec<_> = vec![1.5f32, 300.0].into_iter().approx_into_each::<u8>().collect();
+    assert_eq!(v, [Ok(1), Err(FloatError::PosOverflow(300.0))]);
+
+    let v: Vec<_> = vec![1.5f32, -2.5].into_iter().approx_into_each_by::<i8, RoundToNearestEven>().collect();
+    assert_eq!(v, [Ok(2), Ok(-2)]);
+
+    let v: Vec<_> = vec![300u16, 257].into_iter().approx_into_each_by::<u8, Wrapping>()
+        .map(UnwrapOk::unwrap_ok).collect();
+    assert_eq!(v, [44, 1]);
+}
+
+#[test]
+fn test_saturate_each() {
+    let v: Vec<_> = vec![-1i32, 5, 256].into_iter().value_into_each::<u8>().saturate_each()
+        .map(UnwrapOk::unwrap_ok).collect();
+    assert_eq!(v, [0, 5, 255]);
+
+    let v: Vec<_> = vec![-1e9f64, 5.5, 1e9].into_iter().approx_into_each::<i16>().saturate_each().collect();
+    assert_eq!(v, [Ok(-32768), Ok(5), Ok(32767)]);
+
+    let r = vec![::std::f64::NAN].into_iter().approx_into_each::<i16>().saturate_each().next().unwrap();
+    assert!(r.is_err());
+}
+
+#[test]
+fn test_try_collect_conv() {
+    let v: Result<Vec<u8>, _> = vec![1i32, 2, 3].into_iter().value_into_each().try_collect_conv();
+    assert_eq!(v, Ok(vec![1, 2, 3]));
+
+    let v = vec![1i32, 2, -3, 400].into_iter().value_into_each::<u8>().try_collect_conv::<Vec<_>>();
+    assert_eq!(v, Err(IndexedError::new(2, RangeError::NegOverflow(-3))));
+    assert_eq!(v.unwrap_err().to_string(), "element 2: conversion resulted in negative overflow");
+
+    let v: Result<String, IndexedError<_>> = vec![104u32, 105, 0xd800].into_iter()
+        .map(|c| char::try_from(c)).try_collect_conv();
+    assert_eq!(v.unwrap_err().index(), 2);
+
+    let v = Vec::<i32>::new().into_iter().value_into_each::<u8>().try_collect_conv::<Vec<_>>();
+    assert_eq!(v, Ok(vec![]));
+}
+
+#[test]
+fn test_try_collect_conv_stops_at_error() {
+    let mut seen = vec![];
+    let v = vec![1i32, -1, 2].into_iter()
+        .inspect(|&x| seen.push(x))
+        .value_into_each::<u8>()
+        .try_collect_conv::<Vec<_>>();
+    assert_eq!(v.unwrap_err().index(), 1);
+    assert_eq!(seen, [1, -1]);
+}
@@ -7,6 +7,9 @@ use conv::*;
 
 use conv::FloatError::NegOverflow as FU;
 use conv::FloatError::PosOverflow as FO;
+use conv::FloatValueError::NegOverflow as VU;
+use conv::FloatValueError::PosOverflow as VO;
+use conv::FloatValueError::Inexact as VI;
 
 #[test]
 fn test_f32() {
@@ -16,43 +19,238 @@ fn test_f32() {
 
 #[test]
 fn test_f32_to_int() {
-    check!(f32, i8=>  sidenta; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
-    check!(f32, i16=> sidenta; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
-    check!(f32, i32=> sidenta; qa: i32=>
+    check!(f32, i8=>  sidenta; qaS: f; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
+    check!(f32, i16=> sidenta; qaS: f; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
+    check!(f32, i32=> sidenta; qaS: f; qa: i32=>
         a: -2.1474836e9, -2147483648; a: 2.1474835e9, 2147483520;
         a: -2_147_500_000.0, !FU; a: 2_147_500_000.0, !FO;);
-    check!(f32, i64=> sidenta; qa: i64=>
+    check!(f32, i64=> sidenta; qaS: f; qa: i64=>
         a: -9.223372e18, -9223372036854775808; a: 9.2233715e18, 9223371487098961920;
         a: -9_223_373_000_000_000_000.0, !FU; a: 9_223_373_000_000_000_000.0, !FO;);
-    check!(f32, u8=>  uidenta; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
-    check!(f32, u16=> uidenta; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
-    check!(f32, u32=> uidenta; qa: u32=>
+    check!(f32, i128=> sidenta; qaS: f; qa: i128=>
+        a: -1.7014118e38, -170141183460469231731687303715884105728;
+        a: 1.7014117e38, 170141173319264429905852091742258462720;
+        a: -1.701412e38, !FU; a: 1.7014118e38, !FO;);
+    check!(f32, u8=>  uidenta; qaS: f; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
+    check!(f32, u16=> uidenta; qaS: f; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
+    check!(f32, u32=> uidenta; qaS: f; qa: u32=>
         a: 4.294967e9, 4294967040;
         a: -1.0, !FU; a: 4_294_968_000.0, !FO;);
-    check!(f32, u64=> uidenta; qa: u64=>
+    check!(f32, u64=> uidenta; qaS: f; qa: u64=>
         a: 1.8446743e19, 18446742974197923840;
         a: -1.0, !FU; a: 18_446_746_000_000_000_000.0, !FO;);
+    check!(f32, u128=> uidenta; qaS: f; qa: u128=>
+        a: 3.4028235e38, 340282346638528859811704183484516925440;
+        a: -1.0, !FU; a: std::f32::INFINITY, !FO;);
 }
 
 #[test]
 fn test_f64_to_int() {
-    check!(f64, i8=>  sidenta; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
-    check!(f64, i16=> sidenta; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
-    check!(f64, i32=> sidenta; qa: i32=> a: -2_147_483_649.0, !FU; a: 2_147_483_648.0, !FO;);
-    check!(f64, i64=> sidenta; qa: i64=>
+    check!(f64, i8=>  sidenta; qaS: f; qa: i8=>  a: -129.0, !FU; a: 128.0, !FO;);
+    check!(f64, i16=> sidenta; qaS: f; qa: i16=> a: -32_769.0, !FU; a: 32_768.0, !FO;);
+    check!(f64, i32=> sidenta; qaS: f; qa: i32=> a: -2_147_483_649.0, !FU; a: 2_147_483_648.0, !FO;);
+    check!(f64, i64=> sidenta; qaS: f; qa: i64=>
         a: -9.223372036854776e18, -9223372036854775808;
         a: 9.223372036854775e18, 9223372036854774784;
         a: -9_223_372_036_854_778_000.0, !FU; a: 9_223_372_036_854_778_000.0, !FO;);
-    check!(f64, u8=>  uidenta; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
-    check!(f64, u16=> uidenta; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
-    check!(f64, u32=> uidenta; qa: u32=> a: -1.0, !FU; a: 4_294_967_296.0, !FO;);
-    check!(f64, u64=> uidenta; qa: u64=>
+    check!(f64, i128=> sidenta; qaS: f; qa: i128=>
+        a: -1.7014118346046923e38, -170141183460469231731687303715884105728;
+        a: 1.7014118346046921e38, 170141183460469212842221372237303250944;
+        a: -1.7014118346046926e38, !FU; a: 1.7014118346046923e38, !FO;);
+    check!(f64, u8=>  uidenta; qaS: f; qa: u8=>  a: -1.0, !FU; a: 256.0, !FO;);
+    check!(f64, u16=> uidenta; qaS: f; qa: u16=> a: -1.0, !FU; a: 65_536.0, !FO;);
+    check!(f64, u32=> uidenta; qaS: f; qa: u32=> a: -1.0, !FU; a: 4_294_967_296.0, !FO;);
+    check!(f64, u64=> uidenta; qaS: f; qa: u64=>
         a: 1.844674407370955e19;
         a: -1.0, !FU; a: 18_446_744_073_709_560_000.0, !FO;);
+    check!(f64, u128=> uidenta; qaS: f; qa: u128=>
+        a: 3.4028236692093843e38, 340282366920938425684442744474606501888;
+        a: -1.0, !FU; a: 3.402823669209385e38, !FO;);
+}
+
+#[test]
+fn test_f32_to_int_value() {
+    check!(f32, i8=> fident; v: -128.0; v: 127.0;
+        v: 0.5, !VI; v: -0.5, !VI; v: -129.0, !VU; v: 128.0, !VO; v: 127.5, !VO;);
+    check!(f32, i16=> fident; v: -32_768.0; v: 32_767.0;
+        v: 0.5, !VI; v: -32_769.0, !VU; v: 32_768.0, !VO;);
+    check!(f32, i32=> fident; v: -2.1474836e9f32; v: 2.1474835e9f32; v: 8_388_607.0;
+        v: 8_388_607.5, !VI; v: -2_147_500_000.0, !VU; v: 2.1474836e9, !VO;);
+    check!(f32, i64=> fident; v: -9.223372e18f32; v: 9.2233715e18f32;
+        v: 0.25, !VI; v: -9_223_373_000_000_000_000.0, !VU; v: 9.223372e18, !VO;);
+    check!(f32, i128=> fident; v: -1.7014118e38f32; v: 1.7014117e38f32;
+        v: -1.5, !VI; v: -1.701412e38, !VU; v: 1.7014118e38, !VO;);
+    check!(f32, u8=> v: 0.0; v: 1.0; v: 255.0;
+        v: 0.5, !VI; v: -1.0, !VU; v: 256.0, !VO;);
+    check!(f32, u16=> v: 0.0; v: 1.0; v: 65_535.0;
+        v: 0.5, !VI; v: -1.0, !VU; v: 65_536.0, !VO;);
+    check!(f32, u32=> v: 0.0; v: 1.0; v: 4.294967e9f32;
+        v: 0.5, !VI; v: -1.0, !VU; v: 4_294_968_000.0, !VO;);
+    check!(f32, u64=> v: 0.0; v: 1.0; v: 1.8446743e19f32;
+        v: 0.5, !VI; v: -1.0, !VU; v: 18_446_746_000_000_000_000.0, !VO;);
+    check!(f32, u128=> v: 0.0; v: 1.0; v: 3.4028235e38f32;
+        v: 0.5, !VI; v: -1.0, !VU; v: std::f32::INFINITY, !VO;);
+
+    assert_eq!(u8::value_from(std::f32::NEG_INFINITY), Err(VU(std::f32::NEG_INFINITY)));
+    match i32::value_from(std::f32::NAN) {
+        Err(FloatValueError::NotANumber(v)) => assert!(v.is_nan()),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_f64_to_int_value() {
+    check!(f64, i8=> fident; v: -128.0; v: 127.0;
+        v: 0.5, !VI; v: -0.5, !VI; v: -129.0, !VU; v: 128.0, !VO; v: 127.5, !VO;);
+    check!(f64, i16=> fident; v: -32_768.0; v: 32_767.0;
+        v: 0.5, !VI; v: -32_769.0, !VU; v: 32_768.0, !VO;);
+    check!(f64, i32=> fident; v: -2_147_483_648.0; v: 2_147_483_647.0;
+        v: 2_147_483_646.5, !VI; v: -2_147_483_649.0, !VU; v: 2_147_483_648.0, !VO;);
+    check!(f64, i64=> fident; v: -9.223372036854776e18; v: 9.223372036854775e18;
+        v: 4_503_599_627_370_495.5, !VI;
+        v: -9_223_372_036_854_778_000.0, !VU; v: 9.223372036854776e18, !VO;);
+    check!(f64, i128=> fident; v: -1.7014118346046923e38; v: 1.7014118346046921e38;
+        v: -1.5, !VI; v: -1.7014118346046926e38, !VU; v: 1.7014118346046923e38, !VO;);
+    check!(f64, u8=> v: 0.0; v: 1.0; v: 255.0;
+        v: 0.5, !VI; v: -1.0, !VU; v: 256.0, !VO;);
+    check!(f64, u16=> v: 0.0; v: 1.0; v: 65_535.0;
+        v: 0.5, !VI; v: -1.0, !VU; v: 65_536.0, !VO;);
+    check!(f64, u32=> v: 0.0; v: 1.0; v: 4_294_967_295.0;
+        v: 4_294_967_294.5, !VI; v: -1.0, !VU; v: 4_294_967_296.0, !VO;);
+    check!(f64, u64=> v: 0.0; v: 1.0; v: 1.844674407370955e19;
+        v: 0.5, !VI; v: -1.0, !VU; v: 18_446_744_073_709_560_000.0, !VO;);
+    check!(f64, u128=> v: 0.0; v: 1.0; v: 3.4028236692093843e38;
+        v: 0.5, !VI; v: -1.0, !VU; v: 3.402823669209385e38, !VO;);
+
+    match u64::value_from(std::f64::NAN) {
+        Err(FloatValueError::NotANumber(v)) => assert!(v.is_nan()),
+        r => panic!("unexpected result: {:?}", r),
+    }
+}
+
+#[test]
+fn test_round_to_nearest_even() {
+    check!(f32, i8=> aRNE: 125.5, 126; aRNE: 126.5, 126; aRNE: 127.49, 127;
+        aRNE: 127.5, !FO; aRNE: -127.5, -128; aRNE: -128.5, -128; aRNE: -128.51, !FU;);
+    check!(f32, u8=> aRNE: 254.5, 254; aRNE: 255.5, !FO; aRNE: -0.5, 0; aRNE: -0.51, !FU;);
+    check!(f32, i16=> aRNE: 32_766.5, 32_766; aRNE: 32_767.5, !FO; aRNE: -32_768.5, -32_768;);
+    check!(f32, u16=> aRNE: 65_534.5, 65_534; aRNE: 65_535.5, !FO;);
+    check!(f32, i32=> aRNE: 8_388_606.5, 8_388_606; aRNE: 8_388_607.5, 8_388_608;);
+    check!(f32, u32=> aRNE: 8_388_606.5, 8_388_606; aRNE: 8_388_607.5, 8_388_608;);
+    check!(f32, i64=> aRNE: -8_388_606.5, -8_388_606; aRNE: -8_388_607.5, -8_388_608;);
+    check!(f32, u64=> aRNE: 16_777_216.0, 16_777_216;);
+    check!(f32, i128=> aRNE: -2.5, -2; aRNE: 1.7014117e38, 170141173319264429905852091742258462720;);
+    check!(f32, u128=> aRNE: 2.5, 2; aRNE: 3.4028235e38, 340282346638528859811704183484516925440;);
+
+    check!(f64, i8=> aRNE: 126.5, 126; aRNE: 127.5, !FO; aRNE: -128.5, -128;);
+    check!(f64, u8=> aRNE: 254.5, 254; aRNE: 255.5, !FO; aRNE: -0.5, 0;);
+    check!(f64, i16=> aRNE: 32_766.5, 32_766; aRNE: 32_767.5, !FO; aRNE: -32_768.5, -32_768;);
+    check!(f64, u16=> aRNE: 65_534.5, 65_534; aRNE: 65_535.5, !FO;);
+    check!(f64, i32=> aRNE: 2_147_483_646.5, 2_147_483_646; aRNE: 2_147_483_647.5, !FO;
+        aRNE: -2_147_483_647.5, -2_147_483_648; aRNE: -2_147_483_648.5, -2_147_483_648;);
+    check!(f64, u32=> aRNE: 4_294_967_294.5, 4_294_967_294; aRNE: 4_294_967_295.5, !FO;);
+    check!(f64, i64=> aRNE: 4_503_599_627_370_494.5, 4_503_599_627_370_494;
+        aRNE: 4_503_599_627_370_495.5, 4_503_599_627_370_496;);
+    check!(f64, u64=> aRNE: 4_503_599_627_370_495.5, 4_503_599_627_370_496;);
+    check!(f64, i128=> aRNE: -0.5, 0; aRNE: -1.5, -2;);
+    check!(f64, u128=> aRNE: 0.5, 0; aRNE: 1.5, 2;);
+}
+
+#[test]
+fn test_saturating() {
+    check!(f32, i8=> aS: -129.0, -128; aS: -128.5, -128; aS: 127.5, 127; aS: 128.0, 127;
+        aS: std::f32::NEG_INFINITY, -128; aS: std::f32::INFINITY, 127;);
+    check!(f32, u8=> aS: -1.0, 0; aS: 256.0, 255;);
+    check!(f32, i32=> aS: 2_147_483_648.0, 2_147_483_647; aS: -2_147_483_904.0, -2_147_483_648;);
+    check!(f32, u128=> aS: std::f32::INFINITY, 340_282_366_920_938_463_463_374_607_431_768_211_455;);
+    check!(f64, i64=> aS: 9.223372036854775807e18, 9_223_372_036_854_775_807;);
+    check!(f64, u64=> aS: 1e20, 18_446_744_073_709_551_615;);
+    check!(f64, f32=> aS: 1e39, std::f32::MAX; aS: -1e39, std::f32::MIN;
+        aS: std::f64::INFINITY, std::f32::INFINITY;);
+
+    assert!(std::f32::NAN.approx_as_by::<u8, Saturating>().is_err());
+    assert!(std::f64::NAN.approx_as_by::<i64, Saturating>().is_err());
+    assert!(std::f64::NAN.approx_as_by::<f32, Saturating>().unwrap_ok().is_nan());
 }
 
 #[test]
 fn test_f64() {
-    check!(f64, f32=> fidenta; qa: *;);
+    check!(f64, f32=> fident; fidenta; qa: *;
+        v: 0.5; v: 16_777_216.0; v: std::f64::INFINITY; v: std::f64::NEG_INFINITY;
+        v: 0.1, !Inexact; v: 16_777_217.0, !Inexact; v: 1e300, !Inexact;);
     check!(f64, f64=> fident; qv: *;);
+
+    assert!(f32::value_from(std::f64::NAN).unwrap().is_nan());
+}
+
+#[test]
+fn test_f64_to_f32_rounding() {
+    check!(f64, f32=>
+        aRTN: 0.5, 0.5; aRNI: 0.5, 0.5; aRPI: 0.5, 0.5; aRTZ: 0.5, 0.5;
+
+        aRTN: 1.0000000009313226, 1.0;
+        aRNI: 1.0000000009313226, 1.0;
+        aRPI: 1.0000000009313226, 1.0000001;
+        aRTZ: 1.0000000009313226, 1.0;
+
+        aRTN: -1.0000000009313226, -1.0;
+        aRNI: -1.0000000009313226, -1.0000001;
+        aRPI: -1.0000000009313226, -1.0;
+        aRTZ: -1.0000000009313226, -1.0;
+
+        aRTN: 1.0000000596046448, 1.0000001;
+        aRNI: 1.0000000596046448, 1.0;
+        aRPI: 1.0000000596046448, 1.0000001;
+        aRTZ: 1.0000000596046448, 1.0;
+
+        aRTN: -1.0000000596046448, -1.0000001;
+        aRNI: -1.0000000596046448, -1.0000001;
+        aRPI: -1.0000000596046448, -1.0;
+        aRTZ: -1.0000000596046448, -1.0;
+
+        aRTN: 1.0000001788139343, 1.0000002;
+        aRTN: -1.0000001788139343, -1.0000002;
+
+        aRTN: 7.006492321624085e-46, 1e-45;
+        aRNI: 7.006492321624085e-46, 0.0;
+        aRPI: 7.006492321624085e-46, 1e-45;
+        aRTZ: 7.006492321624085e-46, 0.0;
+
+        aRTN: -7.006492321624085e-46, -1e-45;
+        aRNI: -7.006492321624085e-46, -1e-45;
+        aRPI: -7.006492321624085e-46, -0.0;
+        aRTZ: -7.006492321624085e-46, -0.0;
+
+        aRTN: 3.4028235677973362e38, std::f32::MAX;
+        aRTZ: 3.4028236692093843e38, std::f32::MAX;
+        aRNI: 3.4028236692093843e38, std::f32::MAX;
+        aRPI: 3.4028234663852886e38, std::f32::MAX;
+        aRTZ: -3.4028236692093843e38, std::f32::MIN;
+        aRPI: -3.4028236692093843e38, std::f32::MIN;
+        aRNI: -3.4028234663852886e38, std::f32::MIN;
+
+        aRTN: std::f64::INFINITY, std::f32::INFINITY;
+        aRTZ: std::f64::NEG_INFINITY, std::f32::NEG_INFINITY;
+    );
+
+    macro_rules! overflows {
+        ($($scheme:ty: $src:expr, $err:path;)*) => {
+            $(
+                assert_eq!(<f32 as ApproxFrom<f64, $scheme>>::approx_from($src), Err($err($src)));
+            )*
+        };
+    }
+
+    overflows! {
+        RoundToNearest: 3.4028235677973366e38, RangeError::PosOverflow;
+        RoundToNearest: -3.4028235677973366e38, RangeError::NegOverflow;
+        RoundToNegInf: 3.4028236692093848e38, RangeError::PosOverflow;
+        RoundToNegInf: -3.402823466385289e38, RangeError::NegOverflow;
+        RoundToPosInf: 3.402823466385289e38, RangeError::PosOverflow;
+        RoundToPosInf: -3.4028236692093848e38, RangeError::NegOverflow;
+        RoundToZero: 1e39, RangeError::PosOverflow;
+        RoundToZero: -1e39, RangeError::NegOverflow;
+    }
+
+    assert!(<f32 as ApproxFrom<f64, RoundToZero>>::approx_from(std::f64::NAN).unwrap().is_nan());
 }
@@ -0,0 +1,129 @@
+#![cfg(all(feature = "half", feature = "std"))]
+
+extern crate conv;
+extern crate half;
+
+use conv::*;
+use half::{bf16, f16};
+
+fn h(v: f64) -> f16 { f16::from_f64(v) }
+fn b(v: f64) -> bf16 { bf16::from_f64(v) }
+
+#[test]
+fn test_half_to_float() {
+    assert_eq!(f32::value_from(h(1.5)).unwrap_ok(), 1.5f32);
+    assert_eq!(f64::value_from(b(-3.0)).unwrap_ok(), -3.0f64);
+    assert_eq!(h(65504.0).approx_as::<f32>().unwrap_ok(), 65504.0f32);
+    assert!(f32::value_from(f16::NAN).unwrap_ok().is_nan());
+}
+
+#[test]
+fn test_float_to_half() {
+    assert_eq!(f16::value_from(1.5f32), Ok(h(1.5)));
+    assert_eq!(f16::value_from(0.1f64), Err(Inexact(0.1f64)));
+    assert_eq!(bf16::value_from(256.0f32), Ok(b(256.0)));
+    assert_eq!(bf16::value_from(257.0f32), Err(Inexact(257.0f32)));
+    assert!(f16::value_from(::std::f32::NAN).unwrap().is_nan());
+    assert_eq!(f16::value_from(::std::f32::INFINITY), Ok(f16::INFINITY));
+
+    // 2049 lies halfway between 2048 and 2050.
+    assert_eq!(2049.0f32.approx_as::<f16>(), Ok(h(2048.0)));
+    assert_eq!(2049.0f32.approx_as_by::<f16, RoundToNearest>(), Ok(h(2050.0)));
+    assert_eq!((-2049.0f32).approx_as_by::<f16, RoundToNearest>(), Ok(h(-2050.0)));
+    assert_eq!(2049.0f32.approx_as_by::<f16, RoundToNearestEven>(), Ok(h(2048.0)));
+    assert_eq!(2051.0f32.approx_as_by::<f16, RoundToNearestEven>(), Ok(h(2052.0)));
+    assert_eq!(2048.5f64.approx_as_by::<f16, RoundToPosInf>(), Ok(h(2050.0)));
+    assert_eq!(2049.9f64.approx_as_by::<f16, RoundToNegInf>(), Ok(h(2048.0)));
+    assert_eq!((-2049.9f64).approx_as_by::<f16, RoundToZero>(), Ok(h(-2048.0)));
+    assert_eq!(1e-10f64.approx_as_by::<f16, RoundToPosInf>(), Ok(f16::from_bits(1)));
+    assert_eq!((-1e-10f64).approx_as_by::<f16, RoundToZero>(), Ok(h(-0.0)));
+
+    // 65520 lies halfway between `f16::MAX` and the next (unrepresentable) value.
+    assert_eq!(65519.0f32.approx_as::<f16>(), Ok(f16::MAX));
+    assert_eq!(65520.0f32.approx_as::<f16>(), Err(RangeError::PosOverflow(65520.0f32)));
+    assert_eq!(65535.0f32.approx_as_by::<f16, RoundToZero>(), Ok(f16::MAX));
+    assert_eq!(65505.0f32.approx_as_by::<f16, RoundToPosInf>(), Err(RangeError::PosOverflow(65505.0f32)));
+    assert_eq!((-1e6f64).approx_as::<f16>(), Err(RangeError::NegOverflow(-1e6)));
+    assert_eq!((-1e6f64).approx_as_by::<f16, Saturating>(), Ok(f16::MIN));
+    assert_eq!(1e40f64.approx_as_by::<bf16, RoundToNearest>(), Err(RangeError::PosOverflow(1e40)));
+    assert_eq!(1e40f64.approx_as_by::<bf16, Saturating>(), Ok(bf16::MAX));
+}
+
+#[test]
+fn test_half_to_half() {
+    assert_eq!(bf16::value_from(h(2.0)), Ok(b(2.0)));
+    assert_eq!(bf16::value_from(h(1.0009765625)), Err(Inexact(h(1.0009765625))));
+    assert_eq!(h(1.0009765625).approx_as_by::<bf16, RoundToPosInf>(), Ok(b(1.0078125)));
+    assert_eq!(b(1e10).approx_as::<f16>(), Err(RangeError::PosOverflow(b(1e10))));
+    assert_eq!(b(1e10).approx_as_by::<f16, Saturating>(), Ok(f16::MAX));
+}
+
+#[test]
+fn test_half_to_int() {
+    assert_eq!(u8::value_from(h(42.0)), Ok(42u8));
+    assert_eq!(u8::value_from(h(41.5)), Err(FloatValueError::Inexact(h(41.5))));
+    assert_eq!(u8::value_from(h(-1.0)), Err(FloatValueError::NegOverflow(h(-1.0))));
+    assert_eq!(h(41.5).approx_as::<u8>(), Ok(41u8));
+    assert_eq!(h(41.5).approx_as_by::<u8, RoundToNearest>(), Ok(42u8));
+    assert_eq!(h(42.5).approx_as_by::<u8, RoundToNearestEven>(), Ok(42u8));
+    assert_eq!(h(41.5).approx_as_by::<i8, RoundToPosInf>(), Ok(42i8));
+    assert_eq!(h(256.0).approx_as::<u8>(), Err(FloatError::PosOverflow(h(256.0))));
+    assert_eq!(h(256.0).approx_as_by::<u8, Saturating>(), Ok(255u8));
+    assert_eq!(f16::NAN.approx_as_by::<u8, Saturating>().is_err(), true);
+    match f16::NAN.approx_as::<i32>() {
+        Err(FloatError::NotANumber(_)) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+    assert_eq!(b(1e30).approx_as::<u64>(), Err(FloatError::PosOverflow(b(1e30))));
+    assert_eq!(b(1e30).approx_as::<u128>().is_ok(), true);
+}
+
+#[test]
+fn test_int_to_half() {
+    assert_eq!(f16::value_from(255u8).unwrap_ok(), h(255.0));
+    assert_eq!(bf16::value_from(-128i8).unwrap_ok(), b(-128.0));
+    assert_eq!(f16::value_from(2048i16), Ok(h(2048.0)));
+    assert_eq!(f16::value_from(2049i16), Err(Inexact(2049i16)));
+    assert_eq!(f16::value_from(65504u32), Ok(f16::MAX));
+    assert_eq!(f16::value_from(65536u32), Err(Inexact(65536u32)));
+    assert_eq!(bf16::value_from(257i32), Err(Inexact(257)));
+    assert_eq!(bf16::value_from(1u64 << 60), Ok(b((1u64 << 60) as f64)));
+
+    assert_eq!(2049i16.approx_as::<f16>().unwrap_ok(), h(2048.0));
+    assert_eq!(2051i16.approx_as::<f16>().unwrap_ok(), h(2052.0));
+    assert_eq!(65519i32.approx_as::<f16>(), Ok(f16::MAX));
+    assert_eq!(65520i32.approx_as::<f16>(), Err(RangeError::PosOverflow(65520)));
+    assert_eq!((-70000i64).approx_as::<f16>(), Err(RangeError::NegOverflow(-70000)));
+    assert_eq!((-70000i64).approx_as_by::<f16, Saturating>().unwrap_ok(), f16::MIN);
+
+    // Just above a halfway point, which a naive conversion through `f64` would round down.
+    let v = (1u64 << 62) + (1 << 54) + 1;
+    assert_eq!(v.approx_as::<bf16>().unwrap_ok(), b(((1u64 << 62) + (1 << 55)) as f64));
+    assert_eq!(u128::max_value().approx_as::<bf16>(), Err(RangeError::PosOverflow(u128::max_value())));
+
+    assert_eq!(2049i32.approx_as_by::<f16, RoundToNearest>(), Ok(h(2050.0)));
+    assert_eq!(2049i32.approx_as_by::<f16, RoundToNearestEven>(), Ok(h(2048.0)));
+    assert_eq!(2049i32.approx_as_by::<f16, RoundToNegInf>(), Ok(h(2048.0)));
+    assert_eq!(2049i32.approx_as_by::<f16, RoundToPosInf>(), Ok(h(2050.0)));
+    assert_eq!(2049i32.approx_as_by::<f16, RoundToZero>(), Ok(h(2048.0)));
+    assert_eq!((-2049i64).approx_as_by::<f16, RoundToNearest>(), Ok(h(-2050.0)));
+    assert_eq!((-2049i64).approx_as_by::<f16, RoundToNegInf>(), Ok(h(-2050.0)));
+    assert_eq!((-2049i64).approx_as_by::<f16, RoundToZero>(), Ok(h(-2048.0)));
+    assert_eq!(70000u32.approx_as_by::<f16, RoundToZero>(), Ok(f16::MAX));
+    assert_eq!(65505u32.approx_as_by::<f16, RoundToPosInf>(), Err(RangeError::PosOverflow(65505)));
+    assert_eq!((-65505i32).approx_as_by::<f16, RoundToNegInf>(), Err(RangeError::NegOverflow(-65505)));
+
+    assert_eq!(2051i16.approx_as_by::<f16, RoundToNegInf>().unwrap_ok(), h(2050.0));
+    assert_eq!(2051i16.approx_as_by::<f16, RoundToPosInf>().unwrap_ok(), h(2052.0));
+    assert_eq!(257i32.approx_as_by::<bf16, RoundToNearest>().unwrap_ok(), b(258.0));
+    assert_eq!(257i32.approx_as_by::<bf16, RoundToNearestEven>().unwrap_ok(), b(256.0));
+    assert_eq!((-257i32).approx_as_by::<bf16, RoundToZero>().unwrap_ok(), b(-256.0));
+    assert_eq!((-257i32).approx_as_by::<bf16, RoundToNegInf>().unwrap_ok(), b(-258.0));
+}
+
+#[test]
+fn test_half_unwraps() {
+    assert_eq!(1e6f64.approx_as::<f16>().unwrap_or_saturate(), f16::MAX);
+    assert_eq!(1e6f64.approx_as::<f16>().unwrap_or_inf(), f16::INFINITY);
+    assert!(0.1f64.value_as::<f16>().unwrap_or_invalid().is_nan());
+}
@@ -8,89 +8,102 @@ use conv::NegOverflow as Uf;
 use conv::PosOverflow as Of;
 use conv::RangeError::NegOverflow as RU;
 use conv::RangeError::PosOverflow as RO;
+use conv::Inexact as Ix;
 
 #[test]
 fn test_i8() {
-    check!(i8, i8=> sident; qv: *; qa: *; qaW: *);
-    check!(i8, i16=> sident; qv: *; qa: *; qaW: *);
-    check!(i8, i32=> sident; qv: *; qa: *; qaW: *);
-    check!(i8, i64=> sident; qv: *; qa: *; qaW: *);
-    check!(i8, u8=> uident; qv: +; qa: +; qaW: *;
+    check!(i8, i8=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, i16=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, i32=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, i64=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, i128=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, u8=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i8, u16=> uident; qv: +; qa: +; qaW: *;
+    check!(i8, u16=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i8, u32=> uident; qv: +; qa: +; qaW: *;
+    check!(i8, u32=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i8, u64=> uident; qv: +; qa: +; qaW: *;
+    check!(i8, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i8, isize=> sident; qv: *; qa: *; qaW: *);
-    check!(i8, usize=> uident; qv: +; qa: +; qaW: *;
+    check!(i8, u128=> uident; qv: +; qa: +; qaW: *; qaS: *;
+        v: -1, !Uf;
+    );
+    check!(i8, isize=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i8, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
 }
 
 #[test]
 fn test_i16() {
-    check!(i16, i8=> sident; qv: i8=> qa: i8=> qaW: *;
+    check!(i16, i8=> sident; qv: i8=> qa: i8=> qaW: *; qaS: *;
         v: -129, !RU; v: 128, !RO;
     );
-    check!(i16, i16=> sident; qv: *; qa: *; qaW: *);
-    check!(i16, i32=> sident; qv: *; qa: *; qaW: *);
-    check!(i16, i64=> sident; qv: *; qa: *; qaW: *);
-    check!(i16, u8=> uident; qv: u8=> qa: +; qaW: *;
+    check!(i16, i16=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i16, i32=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i16, i64=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i16, i128=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i16, u8=> uident; qv: u8=> qa: +; qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i16, u16=> uident; qv: u16, i16=> qa: +; qaW: *;
+    check!(i16, u16=> uident; qv: u16, i16=> qa: +; qaW: *; qaS: *;
+        v: -1, !Uf;
+    );
+    check!(i16, u32=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i16, u32=> uident; qv: +; qa: +; qaW: *;
+    check!(i16, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i16, u64=> uident; qv: +; qa: +; qaW: *;
+    check!(i16, u128=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i16, isize=> sident; qv: *; qa: *; qaW: *);
-    check!(i16, usize=> uident; qv: +; qa: +; qaW: *;
+    check!(i16, isize=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i16, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
 }
 
 #[test]
 fn test_i32() {
-    check!(i32, i8=> sident; qv: i8=> qa: i8=> qaW: *;
+    check!(i32, i8=> sident; qv: i8=> qa: i8=> qaW: *; qaS: *;
         v: -129, !RU; v: 128, !RO;
     );
-    check!(i32, i16=> sident; qv: i16=> qa: i16=> qaW: *;
+    check!(i32, i16=> sident; qv: i16=> qa: i16=> qaW: *; qaS: *;
         v: -32_769, !RU; v: 32_768, !RO;
     );
-    check!(i32, i32=> sident; qv: *; qa: *; qaW: *);
-    check!(i32, i64=> sident; qv: *; qa: *; qaW: *);
-    check!(i32, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(i32, i32=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i32, i64=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i32, i128=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i32, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i32, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(i32, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i32, u32=> uident; qv: +; qa: +; qaW: *;
+    check!(i32, u32=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
-    check!(i32, u64=> uident; qv: +; qa: +; qaW: *;
+    check!(i32, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
+        v: -1, !Uf;
+    );
+    check!(i32, u128=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
     for_bitness! {
         32 {
-            check!(i32, isize=> sident; qv: *; qa: *; qaW: *);
-            check!(i32, usize=> uident; qv: +; qa: +; qaW: *;
+            check!(i32, isize=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(i32, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
         }
         64 {
-            check!(i32, isize=> sident; qv: *; qa: *; qaW: *);
-            check!(i32, usize=> uident; qv: +; qa: +; qaW: *;
+            check!(i32, isize=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(i32, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
         }
@@ -99,42 +112,46 @@ fn test_i32() {
 
 #[test]
 fn test_i64() {
-    check!(i64, i8=> sident; qv: i8=> qa: i8=> qaW: *;
+    check!(i64, i8=> sident; qv: i8=> qa: i8=> qaW: *; qaS: *;
         v: -129, !RU; v: 128, !RO;
     );
-    check!(i64, i16=> sident; qv: i16=> qa: i16=> qaW: *;
+    check!(i64, i16=> sident; qv: i16=> qa: i16=> qaW: *; qaS: *;
         v: -32_769, !RU; v: 32_768, !RO;
     );
-    check!(i64, i32=> sident; qv: i32=> qa: i32=> qaW: *;
+    check!(i64, i32=> sident; qv: i32=> qa: i32=> qaW: *; qaS: *;
         v: -2_147_483_649, !RU; v: 2_147_483_648, !RO;
     );
-    check!(i64, i64=> sident; qv: *; qa: *; qaW: *;
+    check!(i64, i64=> sident; qv: *; qa: *; qaW: *; qaS: *;
     );
-    check!(i64, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(i64, i128=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(i64, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i64, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(i64, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i64, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+    check!(i64, u32=> uident; qv: u32=> qa: u32=> qaW: *; qaS: *;
         v: -1, !RU;
     );
-    check!(i64, u64=> uident; qv: +; qa: +; qaW: *;
+    check!(i64, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
+        v: -1, !Uf;
+    );
+    check!(i64, u128=> uident; qv: +; qa: +; qaW: *; qaS: *;
         v: -1, !Uf;
     );
     for_bitness! {
         32 {
-            check!(i64, isize=> sident; qv: isize=> qa: isize=> qaW: *;
+            check!(i64, isize=> sident; qv: isize=> qa: isize=> qaW: *; qaS: *;
                 v: -2_147_483_649, !RU; v: 2_147_483_648, !RO;
             );
-            check!(i64, usize=> uident; qv: usize=> qa: usize=> qaW: *;
+            check!(i64, usize=> uident; qv: usize=> qa: usize=> qaW: *; qaS: *;
                 v: -1, !RU; v: 4_294_967_296, !RO;
             );
         }
         64 {
-            check!(i64, isize=> sident; qv: *; qa: *; qaW: *;
+            check!(i64, isize=> sident; qv: *; qa: *; qaW: *; qaS: *;
             );
-            check!(i64, usize=> uident; qv: +; qa: +; qaW: *;
+            check!(i64, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
         }
@@ -143,155 +160,167 @@ fn test_i64() {
 
 #[test]
 fn test_u8() {
-    check!(u8, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+    check!(u8, i8=> uident; qv: +i8=> qa: +i8=> qaW: *; qaS: *;
         v: 127; v: 128, !Of;
     );
-    check!(u8, i16=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, i32=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, i64=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, u8=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, u16=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, u32=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, u64=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, isize=> uident; qv: *; qa: *; qaW: *);
-    check!(u8, usize=> uident; qv: *; qa: *; qaW: *);
+    check!(u8, i16=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, i32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, i64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, i128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, u8=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, u16=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, u32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, u128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, isize=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u8, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
 }
 
 #[test]
 fn test_u16() {
-    check!(u16, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+    check!(u16, i8=> uident; qv: +i8=> qa: +i8=> qaW: *; qaS: *;
         v: 128, !Of;
     );
-    check!(u16, i16=> uident; qv: +i16=> qa: +i16=> qaW: *;
+    check!(u16, i16=> uident; qv: +i16=> qa: +i16=> qaW: *; qaS: *;
         v: 32_768, !Of;
     );
-    check!(u16, i32=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, i64=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(u16, i32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, i64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, i128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: 256, !Of;
     );
-    check!(u16, u16=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, u32=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, u64=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, isize=> uident; qv: *; qa: *; qaW: *);
-    check!(u16, usize=> uident; qv: *; qa: *; qaW: *);
+    check!(u16, u16=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, u32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, u128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, isize=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u16, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
 }
 
 #[test]
 fn test_u32() {
-    check!(u32, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+    check!(u32, i8=> uident; qv: +i8=> qa: +i8=> qaW: *; qaS: *;
         v: 128, !Of;
     );
-    check!(u32, i16=> uident; qv: +i16=> qa: +i16=> qaW: *;
+    check!(u32, i16=> uident; qv: +i16=> qa: +i16=> qaW: *; qaS: *;
         v: 32_768, !Of;
     );
-    check!(u32, i32=> uident; qv: +i32=> qa: +i32=> qaW: *;
+    check!(u32, i32=> uident; qv: +i32=> qa: +i32=> qaW: *; qaS: *;
         v: 2_147_483_648, !Of;
     );
-    check!(u32, i64=> uident; qv: *; qa: *; qaW: *);
-    check!(u32, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(u32, i64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u32, i128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u32, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: 256, !Of;
     );
-    check!(u32, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(u32, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: 65_536, !Of;
     );
-    check!(u32, u32=> uident; qv: *; qa: *; qaW: *);
-    check!(u32, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u32, u32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u32, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u32, u128=> uident; qv: *; qa: *; qaW: *; qaS: *);
     for_bitness! {
         32 {
-            check!(u32, isize=> uident; qv: +isize=> qa: +isize=> qaW: *;
+            check!(u32, isize=> uident; qv: +isize=> qa: +isize=> qaW: *; qaS: *;
                 v: 2_147_483_647; v: 2_147_483_648, !Of;
             );
-            check!(u32, usize=> uident; qv: *; qa: *; qaW: *);
+            check!(u32, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
         }
         64 {
-            check!(u32, isize=> uident; qv: *; qa: *; qaW: *);
-            check!(u32, usize=> uident; qv: *; qa: *; qaW: *);
+            check!(u32, isize=> uident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(u32, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
         }
     }
 }
 
 #[test]
 fn test_u64() {
-    check!(u64, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+    check!(u64, i8=> uident; qv: +i8=> qa: +i8=> qaW: *; qaS: *;
         v: 128, !Of;
     );
-    check!(u64, i16=> uident; qv: +i16=> qa: +i16=> qaW: *;
+    check!(u64, i16=> uident; qv: +i16=> qa: +i16=> qaW: *; qaS: *;
         v: 32_768, !Of;
     );
-    check!(u64, i32=> uident; qv: +i32=> qa: +i32=> qaW: *;
+    check!(u64, i32=> uident; qv: +i32=> qa: +i32=> qaW: *; qaS: *;
         v: 2_147_483_648, !Of;
     );
-    check!(u64, i64=> uident; qv: +i64=> qa: +i64=> qaW: *;
+    check!(u64, i64=> uident; qv: +i64=> qa: +i64=> qaW: *; qaS: *;
         v: 9_223_372_036_854_775_808, !Of;
     );
-    check!(u64, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(u64, i128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u64, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: 256, !Of;
     );
-    check!(u64, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(u64, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: 65_536, !Of;
     );
-    check!(u64, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+    check!(u64, u32=> uident; qv: u32=> qa: u32=> qaW: *; qaS: *;
         v: 4_294_967_296, !Of;
     );
-    check!(u64, u64=> uident; qv: *; qa: *; qaW: *);
+    check!(u64, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(u64, u128=> uident; qv: *; qa: *; qaW: *; qaS: *);
     for_bitness! {
         32 {
-            check!(u64, isize=> uident; qv: +isize=> qa: +isize=> qaW: *;
+            check!(u64, isize=> uident; qv: +isize=> qa: +isize=> qaW: *; qaS: *;
                 v: 2_147_483_648, !Of;
             );
-            check!(u64, usize=> uident; qv: usize=> qa: usize=> qaW: *;
+            check!(u64, usize=> uident; qv: usize=> qa: usize=> qaW: *; qaS: *;
                 v: 4_294_967_296, !Of;
             );
         }
         64 {
-            check!(u64, isize=> uident; qv: +i64=> qa: +i64=> qaW: *;
+            check!(u64, isize=> uident; qv: +i64=> qa: +i64=> qaW: *; qaS: *;
                 v: 9_223_372_036_854_775_808, !Of;
             );
-            check!(u64, usize=> uident; qv: *; qa: *; qaW: *);
+            check!(u64, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
         }
     }
 }
 
 #[test]
 fn test_isize() {
-    check!(isize, i8=> sident; qv: i8=> qa: i8=> qaW: *;
+    check!(isize, i8=> sident; qv: i8=> qa: i8=> qaW: *; qaS: *;
         v: -129, !RU; v: 128, !RO;
     );
-    check!(isize, i16=> sident; qv: i16=> qa: i16=> qaW: *;
+    check!(isize, i16=> sident; qv: i16=> qa: i16=> qaW: *; qaS: *;
         v: -32_769, !RU; v: 32_768, !RO;
     );
-    check!(isize, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(isize, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: -1, !RU; v: 256, !RO;
     );
-    check!(isize, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(isize, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: -1, !RU; v: 65_536, !RO;
     );
-    check!(isize, isize=> sident; qv: *; qa: *; qaW: *);
+    check!(isize, isize=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(isize, i128=> sident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(isize, u128=> uident; qv: +; qa: +; qaW: *; qaS: *;
+        v: -1, !Uf;
+    );
     for_bitness! {
         32 {
-            check!(isize, i32=> sident; qv: *; qa: *; qaW: *);
-            check!(isize, i64=> sident; qv: *; qa: *; qaW: *);
-            check!(isize, u32=> uident; qv: +; qa: +; qaW: *;
+            check!(isize, i32=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(isize, i64=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(isize, u32=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
-            check!(isize, u64=> uident; qv: +; qa: +; qaW: *;
+            check!(isize, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
-            check!(isize, usize=> uident; qv: +; qa: +; qaW: *;
+            check!(isize, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
         }
         64 {
-            check!(isize, i32=> sident; qv: *; qa: *; qaW: *);
-            check!(isize, i64=> sident; qv: *; qa: *; qaW: *);
-            check!(isize, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+            check!(isize, i32=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(isize, i64=> sident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(isize, u32=> uident; qv: u32=> qa: u32=> qaW: *; qaS: *;
                 v: -1, !RU; v: 4_294_967_296, !RO;
             );
-            check!(isize, u64=> uident; qv: +; qa: +; qaW: *;
+            check!(isize, u64=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
-            check!(isize, usize=> uident; qv: +; qa: +; qaW: *;
+            check!(isize, usize=> uident; qv: +; qa: +; qaW: *; qaS: *;
                 v: -1, !Uf;
             );
         }
@@ -300,35 +329,157 @@ fn test_isize() {
 
 #[test]
 fn test_usize() {
-    check!(usize, i8=> uident; qv: +i8=> qa: +i8=> qaW: *;
+    check!(usize, i8=> uident; qv: +i8=> qa: +i8=> qaW: *; qaS: *;
         v: 128, !Of;
     );
-    check!(usize, i16=> uident; qv: +i16=> qa: +i16=> qaW: *;
+    check!(usize, i16=> uident; qv: +i16=> qa: +i16=> qaW: *; qaS: *;
         v: 32_768, !Of;
     );
-    check!(usize, u8=> uident; qv: u8=> qa: u8=> qaW: *;
+    check!(usize, u8=> uident; qv: u8=> qa: u8=> qaW: *; qaS: *;
         v: 256, !Of;
     );
-    check!(usize, u16=> uident; qv: u16=> qa: u16=> qaW: *;
+    check!(usize, u16=> uident; qv: u16=> qa: u16=> qaW: *; qaS: *;
         v: 65_536, !Of;
     );
-    check!(usize, usize=> uident; qv: *; qa: *; qaW: *);
+    check!(usize, usize=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(usize, i128=> uident; qv: *; qa: *; qaW: *; qaS: *);
+    check!(usize, u128=> uident; qv: *; qa: *; qaW: *; qaS: *);
     for_bitness! {
         32 {
-            check!(usize, i32=> uident; qv: +i32=> qa: +i32=> qaW: *);
-            check!(usize, i64=> uident; qv: *; qa: *; qaW: *);
-            check!(usize, u32=> uident; qv: *; qa: *; qaW: *);
-            check!(usize, u64=> uident; qv: *; qa: *; qaW: *);
-            check!(usize, isize=> uident; qv: +isize=> qa: +isize=> qaW: *);
+            check!(usize, i32=> uident; qv: +i32=> qa: +i32=> qaW: *; qaS: *);
+            check!(usize, i64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(usize, u32=> uident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(usize, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(usize, isize=> uident; qv: +isize=> qa: +isize=> qaW: *; qaS: *);
         }
         64 {
-            check!(usize, i32=> uident; qv: +i32=> qa: +i32=> qaW: *);
-            check!(usize, i64=> uident; qv: +i64=> qa: +i64=> qaW: *);
-            check!(usize, u32=> uident; qv: u32=> qa: u32=> qaW: *;
+            check!(usize, i32=> uident; qv: +i32=> qa: +i32=> qaW: *; qaS: *);
+            check!(usize, i64=> uident; qv: +i64=> qa: +i64=> qaW: *; qaS: *);
+            check!(usize, u32=> uident; qv: u32=> qa: u32=> qaW: *; qaS: *;
                 v: 4_294_967_296, !Of;
             );
-            check!(usize, u64=> uident; qv: *; qa: *; qaW: *);
-            check!(usize, isize=> uident; qv: +isize=> qa: +isize=> qaW: *);
+            check!(usize, u64=> uident; qv: *; qa: *; qaW: *; qaS: *);
+            check!(usize, isize=> uident; qv: +isize=> qa: +isize=> qaW: *; qaS: *);
+        }
+    }
+}
+
+#[test]
+fn test_i128() {
+    check!(i128, i8=> sident;
+        v: -128; v: 127; v: -129, !RU; v: 128, !RO;
+        a: -129, !RU; a: 128, !RO; aW: 128, -128; aW: 256, 0;
+        aS: -129, -128; aS: 128, 127; aS: 5, 5;
+    );
+    check!(i128, i16=> sident;
+        v: -32_768; v: 32_767; v: -32_769, !RU; v: 32_768, !RO;
+        a: -32_769, !RU; a: 32_768, !RO; aW: 32_768, -32_768;
+    );
+    check!(i128, i32=> sident;
+        v: -2_147_483_649, !RU; v: 2_147_483_648, !RO;
+        a: -2_147_483_649, !RU; a: 2_147_483_648, !RO; aW: 2_147_483_648, -2_147_483_648;
+    );
+    check!(i128, i64=> sident;
+        v: -9_223_372_036_854_775_808; v: 9_223_372_036_854_775_807;
+        v: -9_223_372_036_854_775_809, !RU; v: 9_223_372_036_854_775_808, !RO;
+        a: -9_223_372_036_854_775_809, !RU; a: 9_223_372_036_854_775_808, !RO;
+        aW: 9_223_372_036_854_775_808, -9_223_372_036_854_775_808;
+    );
+    check!(i128, i128=> sident;
+        v: -170_141_183_460_469_231_731_687_303_715_884_105_728;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_727;
+    );
+    check!(i128, u8=> uident;
+        v: 255; v: -1, !RU; v: 256, !RO;
+        a: -1, !RU; a: 256, !RO; aW: -1, 255; aW: 256, 0;
+        aS: -1, 0; aS: 256, 255; aS: 5, 5;
+    );
+    check!(i128, u16=> uident;
+        v: 65_535; v: -1, !RU; v: 65_536, !RO;
+        a: -1, !RU; a: 65_536, !RO; aW: -1, 65_535;
+    );
+    check!(i128, u32=> uident;
+        v: 4_294_967_295; v: -1, !RU; v: 4_294_967_296, !RO;
+        a: -1, !RU; a: 4_294_967_296, !RO; aW: -1, 4_294_967_295;
+    );
+    check!(i128, u64=> uident;
+        v: 18_446_744_073_709_551_615; v: -1, !RU; v: 18_446_744_073_709_551_616, !RO;
+        a: -1, !RU; a: 18_446_744_073_709_551_616, !RO; aW: -1, 18_446_744_073_709_551_615;
+    );
+    check!(i128, u128=> uident;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_727; v: -1, !Uf;
+        a: -1, !Uf; aW: -1, 340_282_366_920_938_463_463_374_607_431_768_211_455;
+        aS: -1, 0; aS: 170_141_183_460_469_231_731_687_303_715_884_105_727,
+            170_141_183_460_469_231_731_687_303_715_884_105_727;
+    );
+    for_bitness! {
+        32 {
+            check!(i128, isize=> sident;
+                v: -2_147_483_649, !RU; v: 2_147_483_648, !RO;
+            );
+            check!(i128, usize=> uident;
+                v: -1, !RU; v: 4_294_967_296, !RO;
+            );
+        }
+        64 {
+            check!(i128, isize=> sident;
+                v: -9_223_372_036_854_775_809, !RU; v: 9_223_372_036_854_775_808, !RO;
+            );
+            check!(i128, usize=> uident;
+                v: -1, !RU; v: 18_446_744_073_709_551_616, !RO;
+            );
+        }
+    }
+}
+
+#[test]
+fn test_u128() {
+    check!(u128, i8=> uident;
+        v: 127; v: 128, !Of; a: 128, !Of; aW: 128, -128;
+    );
+    check!(u128, i16=> uident;
+        v: 32_767; v: 32_768, !Of; a: 32_768, !Of; aW: 32_768, -32_768;
+    );
+    check!(u128, i32=> uident;
+        v: 2_147_483_647; v: 2_147_483_648, !Of; a: 2_147_483_648, !Of;
+    );
+    check!(u128, i64=> uident;
+        v: 9_223_372_036_854_775_807; v: 9_223_372_036_854_775_808, !Of;
+        a: 9_223_372_036_854_775_808, !Of;
+    );
+    check!(u128, i128=> uident;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_727;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_728, !Of;
+        a: 170_141_183_460_469_231_731_687_303_715_884_105_728, !Of;
+        aW: 340_282_366_920_938_463_463_374_607_431_768_211_455, -1;
+        aS: 340_282_366_920_938_463_463_374_607_431_768_211_455,
+            170_141_183_460_469_231_731_687_303_715_884_105_727;
+    );
+    check!(u128, u8=> uident;
+        v: 255; v: 256, !Of; a: 256, !Of; aW: 256, 0; aW: 257, 1;
+        aS: 256, 255; aS: 5, 5;
+    );
+    check!(u128, u16=> uident;
+        v: 65_535; v: 65_536, !Of; a: 65_536, !Of;
+    );
+    check!(u128, u32=> uident;
+        v: 4_294_967_295; v: 4_294_967_296, !Of; a: 4_294_967_296, !Of;
+    );
+    check!(u128, u64=> uident;
+        v: 18_446_744_073_709_551_615; v: 18_446_744_073_709_551_616, !Of;
+        a: 18_446_744_073_709_551_616, !Of; aW: 18_446_744_073_709_551_616, 0;
+    );
+    check!(u128, u128=> uident;
+        v: 340_282_366_920_938_463_463_374_607_431_768_211_455;
+    );
+    for_bitness! {
+        32 {
+            check!(u128, isize=> uident; v: 2_147_483_648, !Of;);
+            check!(u128, usize=> uident; v: 4_294_967_296, !Of;);
+        }
+        64 {
+            check!(u128, isize=> uident; v: 9_223_372_036_854_775_808, !Of;);
+            check!(u128, usize=> uident; v: 18_446_744_073_709_551_616, !Of;);
         }
     }
 }
@@ -338,41 +489,79 @@ fn test_usize() {
 fn test_i_to_f() {
     check!(i8,  f32=> sident; qv: *; qa: *);
     check!(i16, f32=> sident; qv: *; qa: *);
-    check!(i32, f32=> sident; qv: (+-16_777_216); qa: *;
-        v: -16_777_217, !RU; v: 16_777_217, !RO;
-    );
-    check!(i64, f32=> sident; qv: (+-16_777_216); qa: *;
-        v: -16_777_217, !RU; v: 16_777_217, !RO;
-    );
-    check!(isize, f32=> sident; qv: (+-16_777_216); qa: *;
-        v: -16_777_217, !RU; v: 16_777_217, !RO;
+    check!(i32, f32=> sident; qv: x; qa: *;
+        aS: 16_777_217, 16_777_216.0;
+        v: -16_777_216; v: 16_777_216;
+        v: -16_777_217, !Ix; v: 16_777_217, !Ix;
+        v: 16_777_218; v: 1_073_741_824; v: -2_147_483_648i32;
+        v: 2_147_483_647, !Ix;
+    );
+    check!(i64, f32=> sident; qv: x; qa: *;
+        v: -16_777_217, !Ix; v: 16_777_217, !Ix;
+        v: 1_152_921_504_606_846_976i64; v: -9_223_372_036_854_775_808i64;
+        v: 9_223_372_036_854_775_807, !Ix;
+    );
+    check!(isize, f32=> sident; qv: x; qa: *;
+        v: -16_777_217, !Ix; v: 16_777_217, !Ix;
+        v: 1_073_741_824;
+    );
+    check!(i128, f32=> sident;
+        v: -16_777_216; v: 16_777_216;
+        v: -16_777_217, !Ix; v: 16_777_217, !Ix;
+        v: -170_141_183_460_469_231_731_687_303_715_884_105_728i128;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_727, !Ix;
+        a: -170_141_183_460_469_231_731_687_303_715_884_105_728, -1.7014118e38;
+        a: 170_141_183_460_469_231_731_687_303_715_884_105_727, 1.7014118e38;
     );
 
     check!(u8,  f32=> uident; qv: *; qa: *);
     check!(u16, f32=> uident; qv: *; qa: *);
-    check!(u32, f32=> uident; qv: (, 16_777_216); qa: *;
-        v: 16_777_217, !Of;
-    );
-    check!(u64, f32=> uident; qv: (, 16_777_216); qa: *;
-        v: 16_777_217, !Of;
-    );
-    check!(usize, f32=> uident; qv: (, 16_777_216); qa: *;
-        v: 16_777_217, !Of;
+    check!(u32, f32=> uident; qv: x; qa: *;
+        v: 16_777_217, !Ix; v: 2_147_483_648u32; v: 4_294_967_295, !Ix;
+    );
+    check!(u64, f32=> uident; qv: x; qa: *;
+        v: 16_777_217, !Ix; v: 9_223_372_036_854_775_808u64;
+        v: 18_446_744_073_709_551_615, !Ix;
+    );
+    check!(usize, f32=> uident; qv: x; qa: *;
+        v: 16_777_217, !Ix; v: 2_147_483_648usize;
+    );
+    check!(u128, f32=> uident;
+        v: 16_777_216; v: 16_777_217, !Ix;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_728u128;
+        v: 340_282_346_638_528_859_811_704_183_484_516_925_440u128;
+        v: 340_282_366_920_938_463_463_374_607_431_768_211_455, !Ix;
+        a: 340_282_346_638_528_859_811_704_183_484_516_925_440, 3.4028235e38;
+        a: 340_282_356_779_733_661_637_539_395_458_142_568_447, 3.4028235e38;
+        a: 340_282_356_779_733_661_637_539_395_458_142_568_448, !Of;
+        a: 340_282_366_920_938_463_463_374_607_431_768_211_455, !Of;
+        aS: 340_282_366_920_938_463_463_374_607_431_768_211_455, 3.4028235e38;
     );
 
     check!(i8,  f64=> sident; qv: *; qa: *);
     check!(i16, f64=> sident; qv: *; qa: *);
     check!(i32, f64=> sident; qv: *; qa: *);
-    check!(i64, f64=> sident; qv: (+-9_007_199_254_740_992); qa: *;
-        v: -9_007_199_254_740_993, !RU; v: 9_007_199_254_740_993, !RO;
+    check!(i64, f64=> sident; qv: x; qa: *;
+        v: -9_007_199_254_740_992i64; v: 9_007_199_254_740_992i64;
+        v: -9_007_199_254_740_993, !Ix; v: 9_007_199_254_740_993, !Ix;
+        v: 9_007_199_254_740_994i64; v: 1_152_921_504_606_846_976i64;
+        v: -9_223_372_036_854_775_808i64; v: 9_223_372_036_854_775_807, !Ix;
+    );
+    check!(i128, f64=> sident;
+        v: -9_007_199_254_740_992i128; v: 9_007_199_254_740_992i128;
+        v: -9_007_199_254_740_993, !Ix; v: 9_007_199_254_740_993, !Ix;
+        v: 1_152_921_504_606_846_976i128;
+        v: -170_141_183_460_469_231_731_687_303_715_884_105_728i128;
+        a: -170_141_183_460_469_231_731_687_303_715_884_105_728, -1.7014118346046923e38;
     );
     for_bitness! {
         32 {
             check!(isize, f64=> sident; qv: *; qa: *);
         }
         64 {
-            check!(i64, f64=> sident; qv: (+-9_007_199_254_740_992); qa: *;
-                v: -9_007_199_254_740_993, !RU; v: 9_007_199_254_740_993, !RO;
+            check!(isize, f64=> sident; qv: x; qa: *;
+                v: -9_007_199_254_740_993, !Ix; v: 9_007_199_254_740_993, !Ix;
+                v: 1_152_921_504_606_846_976isize;
             );
         }
     }
@@ -380,16 +569,23 @@ fn test_i_to_f() {
     check!(u8,  f64=> uident; qv: *; qa: *);
     check!(u16, f64=> uident; qv: *; qa: *);
     check!(u32, f64=> uident; qv: *; qa: *);
-    check!(u64, f64=> uident; qv: (, 9_007_199_254_740_992); qa: *;
-        v: 9_007_199_254_740_993, !Of;
+    check!(u64, f64=> uident; qv: x; qa: *;
+        v: 9_007_199_254_740_993, !Ix; v: 1_152_921_504_606_846_976u64;
+        v: 9_223_372_036_854_775_808u64; v: 18_446_744_073_709_551_615, !Ix;
+    );
+    check!(u128, f64=> uident;
+        v: 9_007_199_254_740_992u128; v: 9_007_199_254_740_993, !Ix;
+        v: 340_282_366_920_938_463_463_374_607_431_768_211_455, !Ix;
+        v: 170_141_183_460_469_231_731_687_303_715_884_105_728u128;
+        a: 340_282_366_920_938_463_463_374_607_431_768_211_455, 3.402823669209385e38;
     );
     for_bitness! {
         32 {
             check!(usize, f64=> uident; qv: *; qa: *);
         }
         64 {
-            check!(u64, f64=> uident; qv: (, 9_007_199_254_740_992); qa: *;
-                v: 9_007_199_254_740_993, !Of;
+            check!(usize, f64=> uident; qv: x; qa: *;
+                v: 9_007_199_254_740_993, !Ix; v: 1_152_921_504_606_846_976usize;
             );
         }
     }
@@ -0,0 +1,78 @@
+#![cfg(feature = "nonzero")]
+
+extern crate conv;
+
+use std::num::{NonZeroI8, NonZeroI32, NonZeroU8, NonZeroU32, NonZeroU64, NonZeroUsize};
+use conv::*;
+
+fn nz_u8(v: u8) -> NonZeroU8 { NonZeroU8::new(v).unwrap() }
+fn nz_i8(v: i8) -> NonZeroI8 { NonZeroI8::new(v).unwrap() }
+fn nz_u32(v: u32) -> NonZeroU32 { NonZeroU32::new(v).unwrap() }
+
+#[test]
+fn test_int_to_nonzero() {
+    assert_eq!(NonZeroU32::value_from(7u64), Ok(nz_u32(7)));
+    assert_eq!(NonZeroU32::value_from(0u64), Err(GeneralError::Unrepresentable(0u64)));
+    assert_eq!(NonZeroU32::value_from(1u64 << 32), Err(GeneralError::PosOverflow(1u64 << 32)));
+    assert_eq!(NonZeroU32::value_from(-1i8), Err(GeneralError::NegOverflow(-1i8)));
+    assert_eq!(NonZeroU32::value_from(1u32), Ok(nz_u32(1)));
+    assert_eq!(NonZeroU32::value_from(0u32), Err(Unrepresentable(0u32)));
+    assert_eq!(NonZeroU32::value_from(0u8), Err(Unrepresentable(0u8)));
+    assert_eq!(NonZeroI32::try_from(0i16), Err(Unrepresentable(0i16)));
+    assert_eq!(NonZeroU64::value_from(0usize), Err(Unrepresentable(0usize)));
+    assert_eq!(NonZeroI8::value_from(-128i32), Ok(nz_i8(-128)));
+    assert_eq!(NonZeroI8::value_from(-129i32), Err(GeneralError::NegOverflow(-129i32)));
+    assert_eq!(NonZeroUsize::try_from(3u8), Ok(NonZeroUsize::new(3).unwrap()));
+    assert_eq!(NonZeroUsize::try_from(0i128), Err(GeneralError::Unrepresentable(0i128)));
+
+    let r: Result<NonZeroU64, _> = 42u16.value_into();
+    assert_eq!(r, Ok(NonZeroU64::new(42).unwrap()));
+}
+
+#[test]
+fn test_int_to_nonzero_matches_inner() {
+    // Every source and destination pair must agree with the conversion into the inner type, whichever error type it uses.
+    macro_rules! check {
+        ($($nz:ident($inner:ident)),*; $srcs:tt) => {
+            $( check! { @nz $nz($inner), $srcs } )*
+        };
+
+        (@nz $nz:ident($inner:ident), ($($src:ident),*)) => {
+            $(
+                for &v in &[$src::min_value(), $src::max_value(), 0, 1, $src::max_value() / 2] {
+                    let expected = $inner::value_from(v).ok().and_then(std::num::$nz::new);
+                    assert_eq!(std::num::$nz::value_from(v).ok(), expected,
+                        "{} -> {}: {}", stringify!($src), stringify!($nz), v);
+                }
+            )*
+        };
+    }
+
+    check! {
+        NonZeroI8(i8), NonZeroI16(i16), NonZeroI32(i32), NonZeroI64(i64), NonZeroI128(i128), NonZeroIsize(isize),
+        NonZeroU8(u8), NonZeroU16(u16), NonZeroU32(u32), NonZeroU64(u64), NonZeroU128(u128), NonZeroUsize(usize);
+        (i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize)
+    }
+}
+
+#[test]
+fn test_nonzero_to_int() {
+    assert_eq!(u8::value_from(nz_u8(200)).unwrap_ok(), 200u8);
+    assert_eq!(u64::value_from(nz_u32(9)).unwrap_ok(), 9u64);
+    assert_eq!(i8::value_from(nz_u8(200)), Err(PosOverflow(200u8)));
+    assert_eq!(u32::value_from(nz_i8(-3)), Err(NegOverflow(-3i8)));
+    assert_eq!(u8::value_from(NonZeroI32::new(-300).unwrap()), Err(RangeError::NegOverflow(-300i32)));
+    assert_eq!(i16::try_from(nz_i8(-3)).unwrap_ok(), -3i16);
+    assert_eq!(nz_u8(5).value_as::<u128>().unwrap_ok(), 5u128);
+}
+
+#[test]
+fn test_nonzero_to_nonzero() {
+    assert_eq!(NonZeroU32::value_from(nz_u8(5)).unwrap_ok(), nz_u32(5));
+    assert_eq!(NonZeroU8::value_from(nz_u32(255)), Ok(nz_u8(255)));
+    assert_eq!(NonZeroU8::value_from(nz_u32(256)), Err(PosOverflow(256u32)));
+    assert_eq!(NonZeroU8::value_from(nz_i8(-1)), Err(NegOverflow(-1i8)));
+    assert_eq!(NonZeroI8::try_from(nz_u8(127)), Ok(nz_i8(127)));
+    assert_eq!(NonZeroI8::try_from(nz_u8(128)), Err(PosOverflow(128u8)));
+    assert_eq!(NonZeroI32::value_from(nz_i8(-128)).unwrap_ok(), NonZeroI32::new(-128).unwrap());
+}
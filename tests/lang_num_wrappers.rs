@@ -0,0 +1,45 @@
+extern crate conv;
+
+use std::num::Wrapping as W;
+use conv::*;
+
+#[test]
+fn test_into_wrapping() {
+    assert_eq!(W::<u8>::value_from(200i32), Ok(W(200u8)));
+    assert_eq!(W::<u8>::value_from(300i32), Err(RangeError::PosOverflow(300)));
+    assert_eq!(W::<u8>::value_from(-1i8), Err(NegOverflow(-1)));
+    assert_eq!(W::<u32>::try_from(7u8).unwrap_ok(), W(7u32));
+
+    assert_eq!(300i32.approx_as::<W<u8>>().unwrap_ok(), W(44u8));
+    assert_eq!((-1i8).approx_as::<W<u8>>().unwrap_ok(), W(255u8));
+    assert_eq!(<W<u8> as ApproxFrom<_, Wrapping>>::approx_from(256u16).unwrap_ok(), W(0u8));
+    assert_eq!(<W<u8> as ApproxFrom<_, Saturating>>::approx_from(256u16).unwrap_ok(), W(255u8));
+    assert_eq!(300i32.approx_as::<W<i8>>().unwrap_ok(), W(44i8));
+}
+
+#[test]
+fn test_from_wrapping() {
+    assert_eq!(u64::value_from(W(5u32)).unwrap_ok(), 5u64);
+    assert_eq!(u8::value_from(W(300u32)), Err(PosOverflow(300u32)));
+    assert_eq!(i8::try_from(W(-200i32)), Err(RangeError::NegOverflow(-200i32)));
+    assert_eq!(W(300u32).approx_as::<u8>(), Err(PosOverflow(300u32)));
+    assert_eq!(<u8 as ApproxFrom<_, Wrapping>>::approx_from(W(300u32)).unwrap_ok(), 44u8);
+    assert_eq!(W(300u32).approx_as_by::<u8, Saturating>().unwrap_ok(), 255u8);
+}
+
+#[cfg(feature = "num_saturating")]
+#[test]
+fn test_saturating() {
+    use std::num::Saturating as S;
+
+    assert_eq!(S::<i16>::value_from(40_000i32), Err(RangeError::PosOverflow(40_000)));
+    assert_eq!(S::<i16>::value_from(-5i64), Ok(S(-5i16)));
+    assert_eq!(40_000i32.approx_as::<S<i16>>().unwrap_ok(), S(32_767i16));
+    assert_eq!((-40_000i32).approx_as::<S<i16>>().unwrap_ok(), S(-32_768i16));
+    assert_eq!((-1i8).approx_as::<S<u8>>().unwrap_ok(), S(0u8));
+    assert_eq!(<S<u8> as ApproxFrom<_, Wrapping>>::approx_from(-1i8).unwrap_ok(), S(255u8));
+
+    assert_eq!(i32::value_from(S(-5i16)).unwrap_ok(), -5i32);
+    assert_eq!(u8::value_from(S(-5i16)), Err(RangeError::NegOverflow(-5i16)));
+    assert_eq!(S(-5i16).approx_as_by::<u8, Saturating>().unwrap_ok(), 0u8);
+}
@@ -0,0 +1,102 @@
+extern crate conv;
+
+use conv::*;
+use conv::norm::{Normalized, SignedNormalized};
+
+#[test]
+fn test_unorm_to_float() {
+    assert_eq!(0u8.approx_as_by::<f32, Normalized>(), Ok(0.0));
+    assert_eq!(255u8.approx_as_by::<f32, Normalized>(), Ok(1.0));
+    assert_eq!(51u8.approx_as_by::<f32, Normalized>(), Ok(0.2));
+    assert_eq!(128u8.approx_as_by::<f64, Normalized>(), Ok(128.0 / 255.0));
+    assert_eq!(65535u16.approx_as_by::<f32, Normalized>(), Ok(1.0));
+    assert_eq!(u32::max_value().approx_as_by::<f32, Normalized>(), Ok(1.0));
+    assert_eq!(u64::max_value().approx_as_by::<f64, Normalized>(), Ok(1.0));
+    assert_eq!(u128::max_value().approx_as_by::<f32, Normalized>(), Ok(1.0));
+    assert_eq!(usize::max_value().approx_as_by::<f64, Normalized<Saturating>>(), Ok(1.0));
+
+    for c in 0..256u32 {
+        let f = (c as u8).approx_as_by::<f32, Normalized>().unwrap_ok();
+        assert_eq!(f, c as f32 / 255.0);
+    }
+}
+
+#[test]
+fn test_snorm_to_float() {
+    assert_eq!(0i8.approx_as_by::<f32, SignedNormalized>(), Ok(0.0));
+    assert_eq!(127i8.approx_as_by::<f32, SignedNormalized>(), Ok(1.0));
+    assert_eq!((-127i8).approx_as_by::<f32, SignedNormalized>(), Ok(-1.0));
+    assert_eq!((-128i8).approx_as_by::<f32, SignedNormalized>(), Ok(-1.0));
+    assert_eq!(i16::min_value().approx_as_by::<f64, SignedNormalized>(), Ok(-1.0));
+    assert_eq!((-16384i16).approx_as_by::<f64, SignedNormalized>(), Ok(-16384.0 / 32767.0));
+    assert_eq!(i64::min_value().approx_as_by::<f64, SignedNormalized>(), Ok(-1.0));
+    assert_eq!(i128::max_value().approx_as_by::<f32, SignedNormalized>(), Ok(1.0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_float_to_unorm() {
+    assert_eq!(0.0f32.approx_as_by::<u8, Normalized>(), Ok(0));
+    assert_eq!((-0.0f32).approx_as_by::<u8, Normalized>(), Ok(0));
+    assert_eq!(1.0f32.approx_as_by::<u8, Normalized>(), Ok(255));
+    assert_eq!(0.5f32.approx_as_by::<u8, Normalized>(), Ok(128));
+    assert_eq!((127.5f32 / 255.0).approx_as_by::<u8, Normalized>(), Ok(128));
+    assert_eq!(0.2f64.approx_as_by::<u16, Normalized>(), Ok(13107));
+    assert_eq!(1.0f64.approx_as_by::<u32, Normalized>(), Ok(u32::max_value()));
+    assert_eq!(1.0f64.approx_as_by::<u64, Normalized>(), Ok(u64::max_value()));
+    assert_eq!(1.0f32.approx_as_by::<u128, Normalized>(), Ok(u128::max_value()));
+    assert_eq!(0.5f64.approx_as_by::<u64, Normalized>(), Ok(1 << 63));
+
+    assert_eq!((-0.1f32).approx_as_by::<u8, Normalized>(), Err(FloatError::NegOverflow(-0.1)));
+    assert_eq!(1.01f32.approx_as_by::<u8, Normalized>(), Err(FloatError::PosOverflow(1.01)));
+    assert_eq!(std::f64::INFINITY.approx_as_by::<u16, Normalized>(),
+        Err(FloatError::PosOverflow(std::f64::INFINITY)));
+    assert!(std::f32::NAN.approx_as_by::<u8, Normalized>().is_err());
+
+    for c in 0..256u32 {
+        let f = (c as u8).approx_as_by::<f32, Normalized>().unwrap_ok();
+        assert_eq!(f.approx_as_by::<u8, Normalized>(), Ok(c as u8));
+    }
+    for c in 0..65536u32 {
+        let f = (c as u16).approx_as_by::<f32, Normalized>().unwrap_ok();
+        assert_eq!(f.approx_as_by::<u16, Normalized>(), Ok(c as u16));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_float_to_snorm() {
+    assert_eq!(0.0f32.approx_as_by::<i8, SignedNormalized>(), Ok(0));
+    assert_eq!(1.0f32.approx_as_by::<i8, SignedNormalized>(), Ok(127));
+    assert_eq!((-1.0f32).approx_as_by::<i8, SignedNormalized>(), Ok(-127));
+    assert_eq!((-0.5f64).approx_as_by::<i8, SignedNormalized>(), Ok(-64));
+    assert_eq!(0.5f64.approx_as_by::<i16, SignedNormalized>(), Ok(16384));
+    assert_eq!(1.0f64.approx_as_by::<i64, SignedNormalized>(), Ok(i64::max_value()));
+    assert_eq!((-1.0f64).approx_as_by::<i64, SignedNormalized>(), Ok(-i64::max_value()));
+    assert_eq!((-1.0f32).approx_as_by::<i128, SignedNormalized>(), Ok(-i128::max_value()));
+
+    assert_eq!((-1.5f32).approx_as_by::<i8, SignedNormalized>(), Err(FloatError::NegOverflow(-1.5)));
+    assert_eq!(2.0f64.approx_as_by::<i32, SignedNormalized>(), Err(FloatError::PosOverflow(2.0)));
+
+    for c in -128..128i32 {
+        let f = (c as i8).approx_as_by::<f32, SignedNormalized>().unwrap_ok();
+        assert_eq!(f.approx_as_by::<i8, SignedNormalized>(), Ok(if c == -128 { -127 } else { c as i8 }));
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_float_to_norm_saturating() {
+    assert_eq!((-0.5f32).approx_as_by::<u8, Normalized<Saturating>>(), Ok(0));
+    assert_eq!(2.0f32.approx_as_by::<u8, Normalized<Saturating>>(), Ok(255));
+    assert_eq!(std::f64::INFINITY.approx_as_by::<u64, Normalized<Saturating>>(), Ok(u64::max_value()));
+    assert_eq!(0.5f32.approx_as_by::<u8, Normalized<Saturating>>(), Ok(128));
+    assert_eq!((-2.0f32).approx_as_by::<i8, SignedNormalized<Saturating>>(), Ok(-127));
+    assert_eq!(std::f32::NEG_INFINITY.approx_as_by::<i32, SignedNormalized<Saturating>>(), Ok(-i32::max_value()));
+    assert_eq!(2.0f64.approx_as_by::<i16, SignedNormalized<Saturating>>(), Ok(32767));
+
+    let nan = std::f32::NAN.approx_as_by::<u8, Normalized<Saturating>>();
+    assert!(nan.is_err());
+    assert_eq!(nan.unwrap_or(0), 0);
+    assert!(std::f64::NAN.approx_as_by::<i8, SignedNormalized<Saturating>>().is_err());
+}
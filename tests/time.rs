@@ -0,0 +1,104 @@
+extern crate conv;
+
+use std::time::Duration;
+use conv::*;
+use conv::time::{Secs, Millis, Micros, Nanos};
+
+fn ms(v: u64) -> Duration { Duration::from_millis(v) }
+
+#[test]
+fn test_int_to_duration() {
+    assert_eq!(Duration::value_from(90u8), Ok(Duration::from_secs(90)));
+    assert_eq!(Duration::value_from(-1i32), Err(RangeError::NegOverflow(-1)));
+    assert_eq!(Duration::value_from(u128::max_value()), Err(RangeError::PosOverflow(u128::max_value())));
+    assert_eq!(1_500u64.approx_as_by::<Duration, Millis>(), Ok(ms(1_500)));
+    assert_eq!(7i16.approx_as_by::<Duration, Micros>(), Ok(Duration::from_micros(7)));
+    assert_eq!(7usize.approx_as_by::<Duration, Nanos>(), Ok(Duration::new(0, 7)));
+    assert_eq!((-7i64).approx_as_by::<Duration, Nanos>(), Err(RangeError::NegOverflow(-7)));
+    assert_eq!(u64::max_value().approx_as_by::<Duration, Secs>(), Ok(Duration::new(u64::max_value(), 0)));
+    assert_eq!(u64::max_value().approx_as_by::<Duration, Millis>(), Ok(ms(u64::max_value())));
+    assert_eq!(u128::max_value().approx_as_by::<Duration, Nanos>(),
+        Err(RangeError::PosOverflow(u128::max_value())));
+}
+
+#[test]
+fn test_duration_to_int() {
+    assert_eq!(u32::value_from(Duration::from_secs(90)), Ok(90));
+    assert_eq!(u32::value_from(ms(1_500)), Err(GeneralError::Unrepresentable(ms(1_500))));
+    assert_eq!(u8::value_from(Duration::from_secs(256)), Err(GeneralError::PosOverflow(Duration::from_secs(256))));
+
+    assert_eq!(ms(1_999).approx_as_by::<u64, Secs>(), Ok(1));
+    assert_eq!(ms(1_999).approx_as_by::<u64, Secs<RoundToZero>>(), Ok(1));
+    assert_eq!(ms(1_001).approx_as_by::<u64, Secs<RoundToPosInf>>(), Ok(2));
+    assert_eq!(ms(1_500).approx_as_by::<u64, Secs<RoundToNearest>>(), Ok(2));
+    assert_eq!(ms(1_499).approx_as_by::<u64, Secs<RoundToNearest>>(), Ok(1));
+    assert_eq!(ms(2_500).approx_as_by::<u64, Secs<RoundToNearestEven>>(), Ok(2));
+    assert_eq!(ms(3_500).approx_as_by::<u64, Secs<RoundToNearestEven>>(), Ok(4));
+    assert_eq!(Duration::new(1, 1).approx_as_by::<u128, Nanos>(), Ok(1_000_000_001));
+    assert_eq!(ms(300).approx_as_by::<u8, Millis>(), Err(PosOverflow(ms(300))));
+    assert_eq!(Duration::from_micros(2_500).approx_as_by::<i16, Millis<RoundToNegInf>>(), Ok(2));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_duration_to_float() {
+    assert_eq!(ms(1_500).approx_as_by::<f64, Secs>(), Ok(1.5));
+    assert_eq!(ms(1_500).approx_as_by::<f32, Millis>(), Ok(1_500.0));
+    assert_eq!(Duration::new(0, 250).approx_as_by::<f64, Micros>(), Ok(0.25));
+    assert_eq!(f64::value_from(ms(1_500)), Ok(1.5));
+    assert_eq!(f64::value_from(ms(100)), Err(Inexact(ms(100))));
+    assert_eq!(f64::value_from(ms(125)), Ok(0.125));
+    assert_eq!(f64::value_from(Duration::new(1 << 60, 1)), Err(Inexact(Duration::new(1 << 60, 1))));
+    assert_eq!(f32::value_from(Duration::new(1, 1)), Err(Inexact(Duration::new(1, 1))));
+    assert_eq!(f64::value_from(Duration::new(1 << 40, 262_144)), Err(Inexact(Duration::new(1 << 40, 262_144))));
+
+    // These are correctly rounded, rather than rounded once to `f64` and again to `f32`.
+    assert_eq!(Duration::new(16_777_217, 0).approx_as_by::<f32, Secs>(), Ok(16_777_216.0));
+    assert_eq!(Duration::new(16_777_217, 1).approx_as_by::<f32, Secs>(), Ok(16_777_218.0));
+    assert_eq!(Duration::new(16_777_217, 1).approx_as_by::<f32, Secs<RoundToNearest>>(), Ok(16_777_218.0));
+    assert_eq!(Duration::new(u64::max_value(), 999_999_999).approx_as_by::<f32, Nanos>(), Ok(1.8446744e28));
+    assert_eq!(Duration::new(0, 1).approx_as_by::<f64, Secs>(), Ok(1e-9));
+    assert_eq!(Duration::new(0, 0).approx_as_by::<f32, Millis>(), Ok(0.0));
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_float_to_duration() {
+    use std::f64::{INFINITY, NAN};
+
+    assert_eq!(1.5f64.approx_as_by::<Duration, Secs>(), Ok(ms(1_500)));
+    assert_eq!(2.5f32.approx_as_by::<Duration, Millis>(), Ok(Duration::from_micros(2_500)));
+    assert_eq!(1.9f64.approx_as_by::<Duration, Nanos>(), Ok(Duration::new(0, 1)));
+    assert_eq!(1.9f64.approx_as_by::<Duration, Nanos<RoundToNearest>>(), Ok(Duration::new(0, 2)));
+    assert_eq!(1.1f64.approx_as_by::<Duration, Nanos<RoundToPosInf>>(), Ok(Duration::new(0, 2)));
+    assert_eq!(2.5f64.approx_as_by::<Duration, Nanos<RoundToNearestEven>>(), Ok(Duration::new(0, 2)));
+    assert_eq!((-0.4f64).approx_as_by::<Duration, Nanos<RoundToNearest>>(), Ok(Duration::new(0, 0)));
+    assert_eq!((-0.4f64).approx_as_by::<Duration, Nanos<RoundToNegInf>>(), Err(FloatError::NegOverflow(-0.4)));
+
+    assert_eq!((-1.0f64).approx_as_by::<Duration, Secs>(), Err(FloatError::NegOverflow(-1.0)));
+    assert_eq!(INFINITY.approx_as_by::<Duration, Secs>(), Err(FloatError::PosOverflow(INFINITY)));
+    assert_eq!(1.9e19f64.approx_as_by::<Duration, Secs>(), Err(FloatError::PosOverflow(1.9e19)));
+    assert_eq!(1.152921504606846976e18f64.approx_as_by::<Duration, Secs>(), Ok(Duration::new(1 << 60, 0)));
+    match NAN.approx_as_by::<Duration, Secs>() {
+        Err(FloatError::NotANumber(_)) => (),
+        r => panic!("unexpected result: {:?}", r),
+    }
+
+    assert_eq!((1.152921504606846976e18f64 + 2048.0).approx_as_by::<Duration, Nanos<RoundToPosInf>>(),
+        Ok(Duration::new(1_152_921_504, 606_849_024)));
+    assert_eq!(5e-324f64.approx_as_by::<Duration, Secs<RoundToPosInf>>(), Ok(Duration::new(0, 1)));
+    assert_eq!(5e-324f64.approx_as_by::<Duration, Secs<RoundToNearest>>(), Ok(Duration::new(0, 0)));
+    assert_eq!((-5e-324f64).approx_as_by::<Duration, Secs<RoundToPosInf>>(), Ok(Duration::new(0, 0)));
+    assert_eq!((-5e-324f64).approx_as_by::<Duration, Secs<RoundToNegInf>>(), Err(FloatError::NegOverflow(-5e-324)));
+    assert_eq!(0.5f64.approx_as_by::<Duration, Nanos<RoundToNearestEven>>(), Ok(Duration::new(0, 0)));
+
+    let x = (1u64 << 40) as f64 + 1.0 / 4096.0;
+    assert_eq!(Duration::value_from(x), Err(FloatValueError::Inexact(x)));
+    assert_eq!(x.approx_as_by::<Duration, Secs>(), Ok(Duration::new(1 << 40, 244_140)));
+    assert_eq!(x.approx_as_by::<Duration, Secs<RoundToNearest>>(), Ok(Duration::new(1 << 40, 244_141)));
+    assert_eq!(Duration::value_from((1u64 << 40) as f64 + 1.0 / 512.0), Ok(Duration::new(1 << 40, 1_953_125)));
+    assert_eq!(Duration::value_from(18_446_744_073_709_551_615.0f64), Err(FloatValueError::PosOverflow(18_446_744_073_709_551_615.0)));
+    assert_eq!(Duration::value_from(0.5f64), Ok(ms(500)));
+    assert_eq!(Duration::value_from(1e-10f64), Err(FloatValueError::Inexact(1e-10)));
+    assert_eq!(Duration::value_from(-2.0f32), Err(FloatValueError::NegOverflow(-2.0)));
+}
@@ -20,9 +20,9 @@ fn test_unwraps() {
     assert_eq!(cty!(128i16.approx_by::<Wrapping>().unwrap_or_saturate(), i8), -128);
 
     assert_eq!(cty!(16_777_216i32.value_into().unwrap(), f32), 16_777_216.0);
-    assert_eq!(cty!(16_777_216i32.value_into().unwrap_or_inf(), f32), 16_777_216.0);
-    assert_eq!(cty!(16_777_217i32.value_into().unwrap_or_inf(), f32), std::f32::INFINITY);
-    assert_eq!(cty!((-16_777_217i32).value_into().unwrap_or_inf(), f32), std::f32::NEG_INFINITY);
+    assert_eq!(cty!(1.5f64.approx_by::<RoundToNearest>().unwrap_or_inf(), f32), 1.5);
+    assert_eq!(cty!(1e40f64.approx_by::<RoundToNearest>().unwrap_or_inf(), f32), std::f32::INFINITY);
+    assert_eq!(cty!((-1e40f64).approx_by::<RoundToNearest>().unwrap_or_inf(), f32), std::f32::NEG_INFINITY);
 
     assert_eq!(cty!(16_777_216i32.value_into().unwrap_or_invalid(), f32), 16_777_216.0);
     assert!(cty!(16_777_217i32.value_into().unwrap_or_invalid(), f32).is_nan());
@@ -30,3 +30,59 @@ fn test_unwraps() {
 
     assert_eq!(cty!(0u8.value_into().unwrap_ok(), u16), 0);
 }
+
+#[test]
+fn test_unwrap_or_saturate_more() {
+    use std::num::Wrapping as W;
+    use std::time::Duration;
+
+    assert_eq!(cty!(1.5f64.approx_by::<RoundToNearest>().unwrap_or_saturate(), f32), 1.5);
+    assert_eq!(cty!(1e40f64.approx_by::<RoundToNearest>().unwrap_or_saturate(), f32), std::f32::MAX);
+    assert_eq!(cty!((-1e40f64).approx_by::<RoundToNearest>().unwrap_or_saturate(), f32), std::f32::MIN);
+    assert_eq!(Err::<f64, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate(), std::f64::MAX);
+    assert_eq!(Err::<f64, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate(), std::f64::MIN);
+
+    assert_eq!(Ok::<_, RangeErrorKind>('x').unwrap_or_saturate(), 'x');
+    assert_eq!(Err::<char, _>(NegOverflow(-1i32)).unwrap_or_saturate(), '\0');
+    assert_eq!(Err::<char, _>(PosOverflow(0x110000u32)).unwrap_or_saturate(), '\u{10FFFF}');
+
+    assert_eq!(Err::<bool, _>(RangeError::NegOverflow(-1i8)).unwrap_or_saturate(), false);
+    assert_eq!(Err::<bool, _>(RangeError::PosOverflow(2i8)).unwrap_or_saturate(), true);
+
+    assert_eq!(Err::<W<u8>, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate(), W(255));
+    assert_eq!(Err::<W<i8>, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate(), W(-128));
+
+    assert_eq!(Err::<Duration, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate(), Duration::new(0, 0));
+    assert_eq!(Err::<Duration, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate(),
+        Duration::new(u64::max_value(), 999_999_999));
+}
+
+#[cfg(feature = "num_saturating")]
+#[test]
+fn test_unwrap_or_saturate_num_saturating() {
+    use std::num::Saturating as S;
+
+    assert_eq!(Err::<S<u16>, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate(), S(65_535));
+    assert_eq!(Err::<S<i16>, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate(), S(-32_768));
+}
+
+#[cfg(feature = "nonzero")]
+#[test]
+fn test_unwrap_or_saturate_nonzero() {
+    use std::num::{NonZeroI8, NonZeroI64, NonZeroU8, NonZeroU32, NonZeroUsize};
+
+    assert_eq!(Err::<NonZeroU8, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate().get(), 1);
+    assert_eq!(Err::<NonZeroU8, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate().get(), 255);
+    assert_eq!(Err::<NonZeroU32, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate().get(), u32::max_value());
+    assert_eq!(Err::<NonZeroUsize, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate().get(), 1);
+    assert_eq!(Err::<NonZeroI8, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate().get(), -128);
+    assert_eq!(Err::<NonZeroI8, _>(RangeErrorKind::PosOverflow).unwrap_or_saturate().get(), 127);
+    assert_eq!(Err::<NonZeroI64, _>(RangeErrorKind::NegOverflow).unwrap_or_saturate().get(), i64::min_value());
+}
+
+#[test]
+fn test_unwrap_or_invalid_more() {
+    assert_eq!(Ok::<_, NoError>(Some(3u8)).unwrap_or_invalid(), Some(3));
+    assert_eq!(Err::<Option<u8>, _>(Unrepresentable(300i32)).unwrap_or_invalid(), None);
+    assert_eq!(Err::<Option<char>, _>(GeneralErrorKind::NotANumber).unwrap_or_invalid(), None);
+}
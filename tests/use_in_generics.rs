@@ -12,3 +12,15 @@ fn test_generic_unwrap() {
 
     assert_eq!({let x: u8 = do_conv(42i32); x}, 42u8);
 }
+
+#[test]
+fn test_generic_saturate() {
+    fn do_conv<T>(t: T) -> u8
+    where T: ApproxInto<u8, Saturating, Err=conv::NoError> {
+        t.approx_into().unwrap_ok()
+    }
+
+    assert_eq!(do_conv(-1i32), 0u8);
+    assert_eq!(do_conv(42u64), 42u8);
+    assert_eq!(do_conv(300i16), 255u8);
+}
@@ -45,6 +45,17 @@ macro_rules! check {
         check!(@ $from, $to=> aRTN: 0.75, 1;);
         check!(@ $from, $to=> aRTN: 1.00, 1;);
 
+        check!(@ $from, $to=> aRNE:  0.00,  0;);
+        check!(@ $from, $to=> aRNE:  0.25,  0;);
+        check!(@ $from, $to=> aRNE:  0.50,  0;);
+        check!(@ $from, $to=> aRNE:  0.75,  1;);
+        check!(@ $from, $to=> aRNE:  1.00,  1;);
+        check!(@ $from, $to=> aRNE:  1.25,  1;);
+        check!(@ $from, $to=> aRNE:  1.50,  2;);
+        check!(@ $from, $to=> aRNE:  1.75,  2;);
+        check!(@ $from, $to=> aRNE:  2.50,  2;);
+        check!(@ $from, $to=> aRNE:  3.50,  4;);
+
         check!(@ $from, $to=> aRNI:  0.00,  0;);
         check!(@ $from, $to=> aRNI:  0.25,  0;);
         check!(@ $from, $to=> aRNI:  0.50,  0;);
@@ -81,6 +92,26 @@ macro_rules! check {
         check!(@ $from, $to=> aRTN:  0.75,  1;);
         check!(@ $from, $to=> aRTN:  1.00,  1;);
 
+        check!(@ $from, $to=> aRNE: -3.50, -4;);
+        check!(@ $from, $to=> aRNE: -2.50, -2;);
+        check!(@ $from, $to=> aRNE: -1.75, -2;);
+        check!(@ $from, $to=> aRNE: -1.50, -2;);
+        check!(@ $from, $to=> aRNE: -1.25, -1;);
+        check!(@ $from, $to=> aRNE: -1.00, -1;);
+        check!(@ $from, $to=> aRNE: -0.75, -1;);
+        check!(@ $from, $to=> aRNE: -0.50,  0;);
+        check!(@ $from, $to=> aRNE: -0.25,  0;);
+        check!(@ $from, $to=> aRNE:  0.00,  0;);
+        check!(@ $from, $to=> aRNE:  0.25,  0;);
+        check!(@ $from, $to=> aRNE:  0.50,  0;);
+        check!(@ $from, $to=> aRNE:  0.75,  1;);
+        check!(@ $from, $to=> aRNE:  1.00,  1;);
+        check!(@ $from, $to=> aRNE:  1.25,  1;);
+        check!(@ $from, $to=> aRNE:  1.50,  2;);
+        check!(@ $from, $to=> aRNE:  1.75,  2;);
+        check!(@ $from, $to=> aRNE:  2.50,  2;);
+        check!(@ $from, $to=> aRNE:  3.50,  4;);
+
         check!(@ $from, $to=> aRNI: -1.00, -1;);
         check!(@ $from, $to=> aRNI: -0.75, -1;);
         check!(@ $from, $to=> aRNI: -0.50, -1;);
@@ -160,42 +191,18 @@ macro_rules! check {
         check!(@ $from, $to=> $($tail)*);
     };
 
-    (@ $from:ty, $to:ty=> qv: (+-$bound:expr); $($tail:tt)*) => {
+    (@ $from:ty, $to:ty=> qv: x; $($tail:tt)*) => {
         {
             extern crate quickcheck;
-            println!("? {} => {}, qv: (+- {})", SL!($from), SL!($to), SL!($bound));
+            println!("? {} => {}, qv: x", SL!($from), SL!($to));
 
             fn property(v: $from) -> bool {
-                let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
-                if !(-$bound as $from <= v) {
-                    dst == Err(conv::FloatError::NegOverflow(v))
-                } else if !(v <= $bound as $from) {
-                    dst == Err(conv::FloatError::PosOverflow(v))
+                let dst: Result<$to, conv::Inexact<_>> = v.value_into();
+                let approx = v as $to;
+                if approx as i128 == v as i128 {
+                    dst == Ok(approx)
                 } else {
-                    dst == Ok(v as $to)
-                }
-            }
-
-            let mut qc = quickcheck::QuickCheck::new();
-            match qc.quicktest(property as fn($from) -> bool) {
-                Ok(_) => (),
-                Err(err) => panic!("qv {:?}", err)
-            }
-        }
-        check!(@ $from, $to=> $($tail)*);
-    };
-
-    (@ $from:ty, $to:ty=> qv: (, $bound:expr); $($tail:tt)*) => {
-        {
-            extern crate quickcheck;
-            println!("? {} => {}, qv: (, {})", SL!($from), SL!($to), SL!($bound));
-
-            fn property(v: $from) -> bool {
-                let dst: Result<$to, conv::FloatError<_>> = v.value_into().map_err(From::from);
-                if !(v <= $bound as $from) {
-                    dst == Err(conv::FloatError::PosOverflow(v))
-                } else {
-                    dst == Ok(v as $to)
+                    dst == Err(conv::Inexact(v))
                 }
             }
 
@@ -443,6 +450,74 @@ macro_rules! check {
         check!(@ $from, $to=> $($tail)*);
     };
 
+    (@ $from:ty, $to:ty=> qaS: *; $($tail:tt)*) => {
+        {
+            extern crate quickcheck;
+            println!("? {} => {}, qaS: *", SL!($from), SL!($to));
+
+            fn property(v: $from) -> bool {
+                let dst: Result<$to, _> = v.approx_as_by::<_, Saturating>();
+                dst == Ok(v.value_as::<$to>().unwrap_or_saturate())
+            }
+
+            let mut qc = quickcheck::QuickCheck::new();
+            match qc.quicktest(property as fn($from) -> bool) {
+                Ok(_) => (),
+                Err(err) => panic!("qaS {:?}", err)
+            }
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> qaS: f; $($tail:tt)*) => {
+        {
+            extern crate quickcheck;
+            println!("? {} => {}, qaS: f", SL!($from), SL!($to));
+
+            fn property(v: $from) -> bool {
+                let dst: Result<$to, _> = v.approx_as_by::<_, Saturating>();
+                dst == v.approx_as::<$to>().saturate()
+            }
+
+            let mut qc = quickcheck::QuickCheck::new();
+            match qc.quicktest(property as fn($from) -> bool) {
+                Ok(_) => (),
+                Err(err) => panic!("qaS {:?}", err)
+            }
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aS: $src:expr, !$dst:expr; $($tail:tt)*) => {
+        {
+            println!("? {} => {}, aS: {}, !{}", SL!($from), SL!($to), SL!($src), SL!($dst));
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_as_by::<_, Saturating>();
+            assert_eq!(dst, Err($dst(src)));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aS: $src:expr, $dst:expr; $($tail:tt)*) => {
+        {
+            println!("? {} => {}, aS: {}, {}", SL!($from), SL!($to), SL!($src), SL!($dst));
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_as_by::<_, Saturating>();
+            assert_eq!(dst, Ok($dst));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aW: $src:expr, $dst:expr; $($tail:tt)*) => {
+        {
+            println!("? {} => {}, aW: {}, {}", SL!($from), SL!($to), SL!($src), SL!($dst));
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_as_by::<_, Wrapping>();
+            assert_eq!(dst, Ok($dst));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
     (@ $from:ty, $to:ty=> aRTN: $src:expr, $dst:expr; $($tail:tt)*) => {
         {
             println!("? {} => {}, aRTN: {}, {}", SL!($from), SL!($to), SL!($src), SL!($dst));
@@ -453,6 +528,26 @@ macro_rules! check {
         check!(@ $from, $to=> $($tail)*);
     };
 
+    (@ $from:ty, $to:ty=> aRNE: $src:expr, !$dst:expr; $($tail:tt)*) => {
+        {
+            println!("? {} => {}, aRNE: {}, !{}", SL!($from), SL!($to), SL!($src), SL!($dst));
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_by::<conv::RoundToNearestEven>();
+            assert_eq!(dst, Err($dst(src)));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
+    (@ $from:ty, $to:ty=> aRNE: $src:expr, $dst:expr; $($tail:tt)*) => {
+        {
+            println!("? {} => {}, aRNE: {}, {}", SL!($from), SL!($to), SL!($src), SL!($dst));
+            let src: $from = $src;
+            let dst: Result<$to, _> = src.approx_by::<conv::RoundToNearestEven>();
+            assert_eq!(dst, Ok($dst));
+        }
+        check!(@ $from, $to=> $($tail)*);
+    };
+
     (@ $from:ty, $to:ty=> aRNI: $src:expr, $dst:expr; $($tail:tt)*) => {
         {
             println!("? {} => {}, aRNI: {}, {}", SL!($from), SL!($to), SL!($src), SL!($dst));